@@ -8,7 +8,7 @@ extern crate lazy_static;
 
 use cheap_ruler::{CheapRuler, DistanceUnit};
 use criterion::Criterion;
-use geo::algorithm::bearing::Bearing;
+use geo::algorithm::haversine_bearing::HaversineBearing;
 use geo::algorithm::haversine_destination::HaversineDestination;
 use geo::algorithm::haversine_distance::HaversineDistance;
 use geo::algorithm::vincenty_distance::VincentyDistance;
@@ -33,7 +33,7 @@ fn vincenty_distance(from: &Point<f64>, to: &Point<f64>) -> f64 {
 }
 
 fn benchmark_distance(c: &mut Criterion) {
-    let input = (STINICA.clone(), MISNJAK.clone());
+    let input = (*STINICA, *MISNJAK);
 
     let mut group = c.benchmark_group("distance");
 
@@ -58,11 +58,11 @@ fn cheap_ruler_bearing(from: &Point<f64>, to: &Point<f64>) -> f64 {
 }
 
 fn geo_bearing(from: &Point<f64>, to: &Point<f64>) -> f64 {
-    from.bearing(to.to_owned())
+    from.haversine_bearing(to.to_owned())
 }
 
 fn benchmark_bearing(c: &mut Criterion) {
-    let input = (STINICA.clone(), MISNJAK.clone());
+    let input = (*STINICA, *MISNJAK);
 
     let mut group = c.benchmark_group("bearing");
 
@@ -95,7 +95,7 @@ fn haversine_destination(
 }
 
 fn benchmark_destination(c: &mut Criterion) {
-    let input = (45.0, 1000., STINICA.clone());
+    let input = (45.0, 1000., *STINICA);
 
     let mut group = c.benchmark_group("destination");
 
@@ -114,10 +114,90 @@ fn benchmark_destination(c: &mut Criterion) {
     group.finish();
 }
 
+fn soa_input() -> (Point<f64>, Vec<Point<f64>>, Vec<f64>, Vec<f64>) {
+    let points: Vec<Point<f64>> = (0..1024)
+        .map(|i| {
+            let t = i as f64 / 1024.0;
+            point!(
+                x: STINICA.x() + (MISNJAK.x() - STINICA.x()) * t,
+                y: STINICA.y() + (MISNJAK.y() - STINICA.y()) * t
+            )
+        })
+        .collect();
+    let xs = points.iter().map(|p| p.x()).collect();
+    let ys = points.iter().map(|p| p.y()).collect();
+    (*STINICA, points, xs, ys)
+}
+
+fn benchmark_square_distances_soa(c: &mut Criterion) {
+    let ruler = CheapRuler::new(STINICA.y(), DistanceUnit::Meters);
+    let (origin, points, xs, ys) = soa_input();
+    let mut out = vec![0.0; points.len()];
+
+    let mut group = c.benchmark_group("square_distances_soa");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (p, o) in points.iter().zip(out.iter_mut()) {
+                *o = ruler.square_distance(&origin, p);
+            }
+        })
+    });
+
+    group.bench_function("soa", |b| {
+        b.iter(|| ruler.square_distances_soa(&origin, &xs, &ys, &mut out))
+    });
+
+    group.finish();
+}
+
+fn benchmark_distances_soa(c: &mut Criterion) {
+    let ruler = CheapRuler::new(STINICA.y(), DistanceUnit::Meters);
+    let (origin, points, xs, ys) = soa_input();
+    let mut out = vec![0.0; points.len()];
+
+    let mut group = c.benchmark_group("distances_soa");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| ruler.distances(&origin, &points, &mut out))
+    });
+
+    group.bench_function("soa", |b| {
+        b.iter(|| ruler.distances_soa(&origin, &xs, &ys, &mut out))
+    });
+
+    group.finish();
+}
+
+fn benchmark_bearings_soa(c: &mut Criterion) {
+    let ruler = CheapRuler::new(STINICA.y(), DistanceUnit::Meters);
+    let (origin, points, xs, ys) = soa_input();
+    let mut out = vec![0.0; points.len()];
+
+    let mut group = c.benchmark_group("bearings_soa");
+
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (p, o) in points.iter().zip(out.iter_mut()) {
+                *o = ruler.bearing(&origin, p);
+            }
+        })
+    });
+
+    group.bench_function("soa", |b| {
+        b.iter(|| ruler.bearings_soa(&origin, &xs, &ys, &mut out))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_distance,
     benchmark_bearing,
     benchmark_destination,
+    benchmark_square_distances_soa,
+    benchmark_distances_soa,
+    benchmark_bearings_soa,
 );
 criterion_main!(benches);
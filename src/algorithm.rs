@@ -0,0 +1,173 @@
+use crate::{CheapRuler, Measure};
+use geo_types::{Line, LineString, Point};
+use num_traits::Float;
+use std::fmt;
+
+/// Calculates the approximate distance between two geometries with a
+/// [`CheapRuler`], shaped like the geo crate's `HaversineDistance`
+pub trait CheapDistance<T, Rhs = Self>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the approximate distance to `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - Geometry to measure the distance to
+    /// * `ruler` - Ruler to measure with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapDistance, CheapRuler, DistanceUnit};
+    /// use geo_types::point;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let a = point!(x: 14.8901816, y: 44.7209699);
+    /// let b = point!(x: 14.8905188, y: 44.7209699);
+    /// assert_eq!(a.cheap_distance(&b, &cr), cr.distance(&a, &b));
+    /// ```
+    fn cheap_distance(&self, rhs: &Rhs, ruler: &CheapRuler<T>) -> T;
+}
+
+impl<T> CheapDistance<T> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_distance(&self, rhs: &Point<T>, ruler: &CheapRuler<T>) -> T {
+        ruler.distance(self, rhs)
+    }
+}
+
+impl<T> CheapDistance<T, Line<T>> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_distance(&self, rhs: &Line<T>, ruler: &CheapRuler<T>) -> T {
+        ruler.point_to_segment_distance(
+            self,
+            &rhs.start_point(),
+            &rhs.end_point(),
+        )
+    }
+}
+
+impl<T> CheapDistance<T, LineString<T>> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the distance to the closest point on the line, or infinity if
+    /// the line is empty
+    fn cheap_distance(&self, rhs: &LineString<T>, ruler: &CheapRuler<T>) -> T {
+        ruler
+            .point_on_line(rhs, self)
+            .map_or(T::infinity(), |pol| ruler.distance(self, &pol.point()))
+    }
+}
+
+/// Calculates the approximate bearing to a point with a [`CheapRuler`],
+/// shaped like the geo crate's `Bearing`
+pub trait CheapBearing<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the approximate bearing to `point` in degrees
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Point to calculate the bearing to
+    /// * `ruler` - Ruler to measure with
+    fn cheap_bearing(&self, point: Point<T>, ruler: &CheapRuler<T>) -> T;
+}
+
+impl<T> CheapBearing<T> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_bearing(&self, point: Point<T>, ruler: &CheapRuler<T>) -> T {
+        ruler.bearing(self, &point)
+    }
+}
+
+/// Calculates the approximate destination from a point with a
+/// [`CheapRuler`], shaped like the geo crate's `HaversineDestination`
+pub trait CheapDestination<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the point reached after travelling `distance` in the
+    /// direction of `bearing`
+    ///
+    /// # Arguments
+    ///
+    /// * `bearing` - Bearing in degrees
+    /// * `distance` - Distance in the ruler's units
+    /// * `ruler` - Ruler to measure with
+    fn cheap_destination(
+        &self,
+        bearing: T,
+        distance: T,
+        ruler: &CheapRuler<T>,
+    ) -> Point<T>;
+}
+
+impl<T> CheapDestination<T> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_destination(
+        &self,
+        bearing: T,
+        distance: T,
+        ruler: &CheapRuler<T>,
+    ) -> Point<T> {
+        ruler.destination(self, distance, bearing)
+    }
+}
+
+/// Calculates the approximate length of a geometry with a [`CheapRuler`],
+/// shaped like the geo crate's `HaversineLength`
+pub trait CheapLength<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the total length of the geometry's lines
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure with
+    fn cheap_length(&self, ruler: &CheapRuler<T>) -> T;
+}
+
+impl<T, G> CheapLength<T> for G
+where
+    T: Float + fmt::Debug,
+    G: Measure<T> + ?Sized,
+{
+    fn cheap_length(&self, ruler: &CheapRuler<T>) -> T {
+        self.length(ruler)
+    }
+}
+
+/// Calculates the approximate area of a geometry with a [`CheapRuler`],
+/// shaped like the geo crate's `ChamberlainDuquetteArea`
+pub trait CheapArea<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the total area of the geometry's polygons
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure with
+    fn cheap_area(&self, ruler: &CheapRuler<T>) -> T;
+}
+
+impl<T, G> CheapArea<T> for G
+where
+    T: Float + fmt::Debug,
+    G: Measure<T> + ?Sized,
+{
+    fn cheap_area(&self, ruler: &CheapRuler<T>) -> T {
+        self.area(ruler)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{interpolate, CheapRuler};
+use geo_types::{LineString, Point};
+use num_traits::Float;
+use std::fmt;
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns a copy of the line with vertices inserted evenly into every
+    /// segment longer than the given length. Existing vertices are kept
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to densify
+    /// * `max_segment_length` - Longest segment length in the result
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_segment_length` is not positive, or if a segment would
+    /// need more pieces than fit in a `usize` (including segments whose
+    /// length isn't finite).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> =
+    ///     vec![(-67.031, 50.458), (-67.031, 50.534)].into();
+    /// let densified = cr.densify(&line_string, 1000.0);
+    /// assert_eq!(densified.0.len(), 10);
+    /// ```
+    pub fn densify(
+        &self,
+        line: &LineString<T>,
+        max_segment_length: T,
+    ) -> LineString<T> {
+        assert!(max_segment_length > T::zero());
+
+        let mut coords = line.0.iter();
+        let mut densified = vec![];
+        let mut p0 = match coords.next() {
+            Some(&c) => Point(c),
+            None => return densified.into(),
+        };
+        densified.push(p0);
+
+        for &c in coords {
+            let p1 = Point(c);
+            let d = self.distance(&p0, &p1);
+            let pieces = (d / max_segment_length)
+                .ceil()
+                .to_usize()
+                .expect("Segment can't be split into that many pieces");
+            for i in 1..pieces {
+                let t = T::from(i).unwrap() / T::from(pieces).unwrap();
+                densified.push(interpolate(&p0, &p1, t));
+            }
+            densified.push(p1);
+            p0 = p1;
+        }
+
+        densified.into()
+    }
+
+    /// Returns points spaced evenly along the line at the given distance
+    /// from its start, followed by the line's last vertex
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to resample
+    /// * `step` - Distance between consecutive points
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let resampled = cr.resample(&line_string, 1000.0);
+    /// assert_eq!(resampled.0.last(), line_string.0.last());
+    /// ```
+    pub fn resample(&self, line: &LineString<T>, step: T) -> LineString<T> {
+        assert!(step > T::zero());
+
+        let mut coords = line.0.iter();
+        let mut resampled = vec![];
+        let mut p0 = match coords.next() {
+            Some(&c) => Point(c),
+            None => return resampled.into(),
+        };
+        resampled.push(p0);
+
+        let mut k = 1;
+        let mut sum = T::zero();
+        for &c in coords {
+            let p1 = Point(c);
+            let d = self.distance(&p0, &p1);
+            sum = sum + d;
+
+            // the same interpolation as along, for each step on the segment
+            let mut dist = T::from(k).unwrap() * step;
+            while dist < sum {
+                let t = (dist - (sum - d)) / d;
+                resampled.push(interpolate(&p0, &p1, t));
+                k += 1;
+                dist = T::from(k).unwrap() * step;
+            }
+            p0 = p1;
+        }
+
+        if line.0.len() > 1 {
+            resampled.push(p0);
+        }
+        resampled.into()
+    }
+}
@@ -15,14 +15,19 @@ pub enum DistanceUnit {
 impl DistanceUnit {
     /// Provides a factor that scales the unit into kilometers
     pub(crate) fn conversion_factor_kilometers<T: Float>(&self) -> T {
+        T::from(self.kilometers_factor()).unwrap()
+    }
+
+    /// Provides a factor that scales the unit into kilometers, as an f64
+    pub(crate) fn kilometers_factor(&self) -> f64 {
         match *self {
-            DistanceUnit::Kilometers => T::one(),
-            DistanceUnit::Miles => T::from(1000f64 / 1609.344).unwrap(),
-            DistanceUnit::NauticalMiles => T::from(1000f64 / 1852.0).unwrap(),
-            DistanceUnit::Meters => T::from(1000f64).unwrap(),
-            DistanceUnit::Yards => T::from(1000f64 / 0.9144).unwrap(),
-            DistanceUnit::Feet => T::from(1000f64 / 0.3048).unwrap(),
-            DistanceUnit::Inches => T::from(1000f64 / 0.0254).unwrap(),
+            DistanceUnit::Kilometers => 1.0,
+            DistanceUnit::Miles => 1000f64 / 1609.344,
+            DistanceUnit::NauticalMiles => 1000f64 / 1852.0,
+            DistanceUnit::Meters => 1000f64,
+            DistanceUnit::Yards => 1000f64 / 0.9144,
+            DistanceUnit::Feet => 1000f64 / 0.3048,
+            DistanceUnit::Inches => 1000f64 / 0.0254,
         }
     }
 }
@@ -0,0 +1,101 @@
+use crate::{FE, RE};
+use num_traits::Float;
+
+/// Defines the reference ellipsoid used to model the shape of the Earth
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ellipsoid {
+    semi_major_axis: f64,
+    flattening: f64,
+}
+
+impl Ellipsoid {
+    /// World Geodetic System 1984, used by GPS
+    pub const WGS84: Ellipsoid = Ellipsoid::new(RE, FE);
+
+    /// Geodetic Reference System 1980, used by NAD83 and ETRS89
+    pub const GRS80: Ellipsoid = Ellipsoid::new(6378.137, 1.0 / 298.257222101);
+
+    /// Clarke 1866 ellipsoid, used by NAD27 and the original FCC formulas
+    pub const CLARKE_1866: Ellipsoid =
+        Ellipsoid::new(6378.2064, 1.0 / 294.978698214);
+
+    /// A sphere with the mean radius of the Earth
+    pub const SPHERE: Ellipsoid = Ellipsoid::new(6371.0088, 0.0);
+
+    /// Creates a custom ellipsoid
+    ///
+    /// # Arguments
+    ///
+    /// * `semi_major_axis` - Equatorial radius in kilometers
+    /// * `flattening` - Flattening, or 0 for a sphere
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Ellipsoid};
+    /// let airy_1830 = Ellipsoid::new(6377.563396, 1.0 / 299.3249646);
+    /// let cr = CheapRuler::with_ellipsoid(
+    ///     51.4779,
+    ///     DistanceUnit::Meters,
+    ///     airy_1830,
+    /// );
+    /// ```
+    pub const fn new(semi_major_axis: f64, flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// Returns the equatorial radius in kilometers
+    pub fn semi_major_axis(&self) -> f64 {
+        self.semi_major_axis
+    }
+
+    /// Returns the flattening
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// Returns the square of the first eccentricity
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    pub(crate) fn semi_major_axis_as<T: Float>(&self) -> T {
+        T::from(self.semi_major_axis).unwrap()
+    }
+
+    pub(crate) fn flattening_as<T: Float>(&self) -> T {
+        T::from(self.flattening).unwrap()
+    }
+
+    pub(crate) fn eccentricity_squared_as<T: Float>(&self) -> T {
+        T::from(self.eccentricity_squared()).unwrap()
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Ellipsoid::WGS84
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ellipsoid_eccentricity() {
+        assert!(
+            (Ellipsoid::WGS84.eccentricity_squared() - 0.00669437999014).abs()
+                < 1e-14
+        );
+        assert!(
+            (Ellipsoid::GRS80.eccentricity_squared() - 0.00669438002290).abs()
+                < 1e-14
+        );
+        assert_eq!(Ellipsoid::SPHERE.eccentricity_squared(), 0.0);
+        assert_eq!(Ellipsoid::default(), Ellipsoid::WGS84);
+    }
+}
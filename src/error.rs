@@ -0,0 +1,56 @@
+use std::error;
+use std::fmt;
+
+/// Errors returned by the fallible constructors, such as
+/// [`CheapRuler::try_new`](crate::CheapRuler::try_new)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Error {
+    /// The zoom level is too large for tile coordinates to fit in a `u32`
+    InvalidZoom(u32),
+    /// The tile row does not exist at the zoom level
+    TileOutOfRange { y: u32, z: u32 },
+    /// The latitude is not finite or is outside of ±90 degrees
+    InvalidLatitude(f64),
+    /// A number could not be represented in the ruler's float type
+    NumericConversion,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidZoom(z) => {
+                write!(f, "invalid zoom level {}, must be less than 32", z)
+            }
+            Error::TileOutOfRange { y, z } => {
+                write!(f, "tile row {} is out of range for zoom level {}", y, z)
+            }
+            Error::InvalidLatitude(latitude) => write!(
+                f,
+                "invalid latitude {}, must be finite and within ±90 degrees",
+                latitude
+            ),
+            Error::NumericConversion => {
+                write!(f, "number cannot be represented in the float type")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            Error::InvalidZoom(32).to_string(),
+            "invalid zoom level 32, must be less than 32"
+        );
+        assert_eq!(
+            Error::TileOutOfRange { y: 4, z: 2 }.to_string(),
+            "tile row 4 is out of range for zoom level 2"
+        );
+    }
+}
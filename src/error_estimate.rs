@@ -0,0 +1,175 @@
+use crate::{
+    curvature_multipliers, long_diff, CheapRuler, Ellipsoid, LngLat, Rect,
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Estimated maximum relative errors of a ruler's distance and area
+/// measurements within an extent, compared to the WGS84 ellipsoid
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ErrorEstimate<T>
+where
+    T: Float + fmt::Debug,
+{
+    distance: T,
+    area: T,
+}
+
+impl<T> ErrorEstimate<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Maximum relative error of distances between points in the extent
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+
+    /// Maximum relative error of areas of polygons in the extent
+    pub fn area(&self) -> T {
+        self.area
+    }
+
+    /// Returns true if all estimated errors are within the given relative
+    /// error
+    ///
+    /// # Arguments
+    ///
+    /// * `max_error` - Largest acceptable relative error
+    pub fn within(&self, max_error: T) -> bool {
+        self.distance <= max_error && self.area <= max_error
+    }
+}
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Estimates the maximum relative errors of measurements made with the
+    /// ruler between points inside the given bounding box
+    ///
+    /// The estimate accounts for the change of the Earth's curvature across
+    /// the latitudes of the box, and for the convergence of meridians across
+    /// its longitudes, which the ruler's flat approximation ignores.
+    ///
+    /// The estimate is only valid for rulers made for the WGS84 ellipsoid,
+    /// such as those from [`CheapRuler::new`]. The ruler doesn't keep its
+    /// ellipsoid, so the latitude it was made for is recovered assuming
+    /// WGS84, and a ruler made with another ellipsoid gets a meaningless
+    /// estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbox` - Bounding box containing the measured points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Rect};
+    /// use geo_types::Coordinate;
+    /// let cr = CheapRuler::new(45.8, DistanceUnit::Meters);
+    /// let city = Rect::new(
+    ///     Coordinate { x: 15.9, y: 45.75 },
+    ///     Coordinate { x: 16.1, y: 45.85 },
+    /// );
+    /// assert!(cr.error_estimate(&city).within(0.001));
+    /// ```
+    pub fn error_estimate(&self, bbox: &Rect<T>) -> ErrorEstimate<T> {
+        let zero = T::zero();
+        let e2 = Ellipsoid::WGS84.eccentricity_squared_as();
+        let (dkx, dky) = curvature_multipliers(self.latitude(), e2);
+
+        let min_lat = bbox.min().y.min(bbox.max().y);
+        let max_lat = bbox.min().y.max(bbox.max().y);
+
+        // multipliers are monotonic in absolute latitude, so the extremes are
+        // at the box's edges or the equator
+        let equator = if min_lat < zero && max_lat > zero {
+            zero
+        } else {
+            min_lat
+        };
+
+        let (curvature, area) = [min_lat, max_lat, equator]
+            .iter()
+            .map(|&latitude| {
+                let (exact_dkx, exact_dky) =
+                    curvature_multipliers(latitude, e2);
+                let error_x = (dkx / exact_dkx - T::one()).abs();
+                let error_y = (dky / exact_dky - T::one()).abs();
+                let error_area =
+                    ((dkx * dky) / (exact_dkx * exact_dky) - T::one()).abs();
+                (error_x.max(error_y), error_area)
+            })
+            .fold((zero, zero), |(c, a), (x, y)| (c.max(x), a.max(y)));
+
+        // relative difference between a parallel and the geodesic connecting
+        // its ends, to the second order of the longitude span
+        let dlon = long_diff(bbox.max().x, bbox.min().x).abs().to_radians();
+        let sin_lat = min_lat.abs().max(max_lat.abs()).to_radians().sin();
+        let convergence = (dlon * sin_lat).powi(2) / T::from(24).unwrap();
+
+        // the errors of both approximations compound, which the square term
+        // bounds from above
+        let distance = curvature + convergence;
+        let distance = distance + distance * distance;
+
+        ErrorEstimate { distance, area }
+    }
+
+    /// Estimates the maximum relative errors of measurements made with the
+    /// ruler between points within a distance span of a center point
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Center of the measured points
+    /// * `span` - Largest distance of a measured point from the center
+    pub fn error_estimate_for_span<P: LngLat<T>>(
+        &self,
+        center: &P,
+        span: T,
+    ) -> ErrorEstimate<T> {
+        self.error_estimate(&self.buffer_point(center, span))
+    }
+
+    /// Returns the absolute latitude the ruler was created for, recovered from
+    /// the ratio of its multipliers, assuming it was made for WGS84
+    fn latitude(&self) -> T {
+        let one = T::one();
+        let e2 = Ellipsoid::WGS84.eccentricity_squared_as::<T>();
+        let r = self.ky / self.kx;
+
+        // solve e2 r c^3 + (1 - e2) r c - (1 - e2) = 0 for the cosine of the
+        // latitude. The function is increasing and convex on [0, 1], so
+        // Newton's method converges from the right.
+        let mut c = one;
+        for _ in 0..16 {
+            let f = e2 * r * c.powi(3) + (one - e2) * r * c - (one - e2);
+            let df = T::from(3).unwrap() * e2 * r * c.powi(2) + (one - e2) * r;
+            let next = c - f / df;
+            if next == c {
+                break;
+            }
+            c = next;
+        }
+
+        c.max(T::zero()).min(one).acos().to_degrees()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DistanceUnit;
+
+    #[test]
+    fn test_ruler_latitude() {
+        for &latitude in [0.0, 12.5, 32.8351, 45.0, 60.0, 85.0].iter() {
+            let ruler = CheapRuler::new(latitude, DistanceUnit::Miles);
+            assert!((ruler.latitude() - latitude).abs() < 1e-5);
+
+            let ruler = CheapRuler::new(-latitude, DistanceUnit::Meters);
+            assert!((ruler.latitude() - latitude).abs() < 1e-5);
+        }
+    }
+}
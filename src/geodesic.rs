@@ -0,0 +1,442 @@
+use crate::lng_lat::to_point;
+use crate::{long_diff, DistanceUnit, Ellipsoid, LngLat};
+use geo_types::Point;
+use num_traits::Float;
+use std::fmt;
+
+/// Largest number of iterations before the inverse solution is considered to
+/// have failed to converge, which happens for nearly antipodal points
+const MAX_ITERATIONS: usize = 200;
+
+/// Convergence threshold for the iterated angles, in radians
+const TOLERANCE: f64 = 1e-12;
+
+/// Exact geodesic measurements on an ellipsoid, WGS84 by default, using
+/// Vincenty's formulas.
+///
+/// Much slower than [`CheapRuler`](crate::CheapRuler), but accurate to within
+/// a millimeter at any distance. Useful as a reference for validating the
+/// approximations, or as a fallback for spans that are too long for them.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Geodesic<T>
+where
+    T: Float + fmt::Debug,
+{
+    a: T,
+    f: T,
+}
+
+/// Solution of the inverse geodesic problem between two points
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GeodesicInverse<T>
+where
+    T: Float + fmt::Debug,
+{
+    distance: T,
+    initial_bearing: T,
+    final_bearing: T,
+}
+
+impl<T> GeodesicInverse<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Length of the geodesic between the points
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+
+    /// Bearing of the geodesic at the first point, in degrees
+    pub fn initial_bearing(&self) -> T {
+        self.initial_bearing
+    }
+
+    /// Bearing of the geodesic at the second point, in degrees
+    pub fn final_bearing(&self) -> T {
+        self.final_bearing
+    }
+}
+
+impl<T> Geodesic<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a geodesic calculator on the WGS84 ellipsoid that expresses
+    /// distances in the given unit
+    ///
+    /// # Arguments
+    ///
+    /// * `distance_unit` - Unit to express distances in
+    pub fn new(distance_unit: DistanceUnit) -> Self {
+        Self::with_ellipsoid(distance_unit, Ellipsoid::WGS84)
+    }
+
+    /// Creates a geodesic calculator on the given ellipsoid that expresses
+    /// distances in the given unit
+    ///
+    /// # Arguments
+    ///
+    /// * `distance_unit` - Unit to express distances in
+    /// * `ellipsoid` - Ellipsoid modelling the shape of the Earth
+    pub fn with_ellipsoid(
+        distance_unit: DistanceUnit,
+        ellipsoid: Ellipsoid,
+    ) -> Self {
+        let a = distance_unit.conversion_factor_kilometers::<T>()
+            * ellipsoid.semi_major_axis_as();
+        let f = ellipsoid.flattening_as();
+
+        Self { a, f }
+    }
+
+    /// Solves the inverse geodesic problem, returning the distance and the
+    /// bearings at both ends of the geodesic between two points. Returns
+    /// `None` if the solution fails to converge, which happens for nearly
+    /// antipodal points.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    pub fn inverse<A, B>(&self, a: &A, b: &B) -> Option<GeodesicInverse<T>>
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let (a, b) = (to_point(a), to_point(b));
+        let zero = T::zero();
+        let one = T::one();
+        let two = T::from(2.0).unwrap();
+        let f = self.f;
+
+        let l = long_diff(b.x(), a.x()).to_radians();
+        let (sin_u1, cos_u1) = reduced_latitude(a.y(), f);
+        let (sin_u2, cos_u2) = reduced_latitude(b.y(), f);
+
+        let mut lambda = l;
+        let mut iterations = 0;
+        let (
+            sin_lambda,
+            cos_lambda,
+            sin_sigma,
+            cos_sigma,
+            sigma,
+            cos_sq_alpha,
+            cos_2sigma_m,
+        ) = loop {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == zero {
+                // coincident points
+                return Some(GeodesicInverse {
+                    distance: zero,
+                    initial_bearing: zero,
+                    final_bearing: zero,
+                });
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = one - sin_alpha * sin_alpha;
+            // equatorial lines have cos_sq_alpha of 0
+            let cos_2sigma_m = if cos_sq_alpha != zero {
+                cos_sigma - two * sin_u1 * sin_u2 / cos_sq_alpha
+            } else {
+                zero
+            };
+
+            let c = lambda_correction(f, cos_sq_alpha);
+            let previous = lambda;
+            lambda = l
+                + (one - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m
+                                + c * cos_sigma
+                                    * (-one + two * cos_2sigma_m.powi(2))));
+
+            iterations += 1;
+            if (lambda - previous).abs() <= T::from(TOLERANCE).unwrap() {
+                break (
+                    sin_lambda,
+                    cos_lambda,
+                    sin_sigma,
+                    cos_sigma,
+                    sigma,
+                    cos_sq_alpha,
+                    cos_2sigma_m,
+                );
+            }
+            if iterations >= MAX_ITERATIONS {
+                return None;
+            }
+        };
+
+        let b_axis = self.a * (one - f);
+        let (big_a, big_b) = series_coefficients(self.a, b_axis, cos_sq_alpha);
+        let delta_sigma =
+            sigma_correction(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+
+        let distance = b_axis * big_a * (sigma - delta_sigma);
+        let initial_bearing = (cos_u2 * sin_lambda)
+            .atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda)
+            .to_degrees();
+        let final_bearing = (cos_u1 * sin_lambda)
+            .atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda)
+            .to_degrees();
+
+        Some(GeodesicInverse {
+            distance,
+            initial_bearing,
+            final_bearing,
+        })
+    }
+
+    /// Calculates the exact distance between two geographical points. Returns
+    /// `None` if the solution fails to converge, which happens for nearly
+    /// antipodal points.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Geodesic};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let geodesic = Geodesic::new(DistanceUnit::Meters);
+    /// let p1 = (14.8901816, 44.7209699);
+    /// let p2 = [14.8905188, 44.7209699];
+    /// let exact: f64 = geodesic.distance(&p1, &p2).unwrap();
+    /// assert!((cr.distance(&p1, &p2) - exact).abs() < 1e-3);
+    /// ```
+    pub fn distance<A, B>(&self, a: &A, b: &B) -> Option<T>
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        self.inverse(a, b).map(|inverse| inverse.distance)
+    }
+
+    /// Returns the initial bearing of the geodesic between two points in
+    /// angles. Returns `None` if the solution fails to converge, which happens
+    /// for nearly antipodal points.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    pub fn bearing<A, B>(&self, a: &A, b: &B) -> Option<T>
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        self.inverse(a, b).map(|inverse| inverse.initial_bearing)
+    }
+
+    /// Returns a new point given distance and bearing from the starting point,
+    /// following the geodesic. Returns `None` if the solution fails to
+    /// converge, which happens for a distance that isn't finite.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing` - bearing
+    pub fn destination<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        dist: T,
+        bearing: T,
+    ) -> Option<Point<T>> {
+        let origin = to_point(origin);
+        let one = T::one();
+        let two = T::from(2.0).unwrap();
+        let f = self.f;
+        let b_axis = self.a * (one - f);
+
+        let (sin_alpha1, cos_alpha1) = bearing.to_radians().sin_cos();
+        let (sin_u1, cos_u1) = reduced_latitude(origin.y(), f);
+        let sigma1 = (sin_u1 / cos_u1).atan2(cos_alpha1);
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos_sq_alpha = one - sin_alpha * sin_alpha;
+        let (big_a, big_b) = series_coefficients(self.a, b_axis, cos_sq_alpha);
+
+        let sigma0 = dist / (b_axis * big_a);
+        let mut sigma = sigma0;
+        let mut iterations = 0;
+        let (sin_sigma, cos_sigma, cos_2sigma_m) = loop {
+            iterations += 1;
+            let cos_2sigma_m = (two * sigma1 + sigma).cos();
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            let delta_sigma =
+                sigma_correction(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+
+            let previous = sigma;
+            sigma = sigma0 + delta_sigma;
+            if (sigma - previous).abs() <= T::from(TOLERANCE).unwrap() {
+                let (sin_sigma, cos_sigma) = sigma.sin_cos();
+                let cos_2sigma_m = (two * sigma1 + sigma).cos();
+                break (sin_sigma, cos_sigma, cos_2sigma_m);
+            }
+            if iterations >= MAX_ITERATIONS {
+                return None;
+            }
+        };
+
+        let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+        let latitude = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+            .atan2((one - f) * (sin_alpha * sin_alpha + x * x).sqrt());
+        let lambda = (sin_sigma * sin_alpha1)
+            .atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = lambda_correction(f, cos_sq_alpha);
+        let l = lambda
+            - (one - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma
+                                * (-one + two * cos_2sigma_m.powi(2))));
+
+        let longitude = long_diff(origin.x() + l.to_degrees(), T::zero());
+        Some(Point::new(longitude, latitude.to_degrees()))
+    }
+}
+
+/// Returns the sine and cosine of the reduced latitude
+fn reduced_latitude<T: Float>(latitude: T, f: T) -> (T, T) {
+    let tan_u = (T::one() - f) * latitude.to_radians().tan();
+    let cos_u = T::one() / (T::one() + tan_u * tan_u).sqrt();
+    (tan_u * cos_u, cos_u)
+}
+
+fn lambda_correction<T: Float>(f: T, cos_sq_alpha: T) -> T {
+    let four = T::from(4.0).unwrap();
+    f / T::from(16.0).unwrap()
+        * cos_sq_alpha
+        * (four + f * (four - T::from(3.0).unwrap() * cos_sq_alpha))
+}
+
+/// Returns Vincenty's A and B series coefficients
+fn series_coefficients<T: Float>(a: T, b: T, cos_sq_alpha: T) -> (T, T) {
+    let c = |v: f64| T::from(v).unwrap();
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let big_a = T::one()
+        + u_sq / c(16384.0)
+            * (c(4096.0)
+                + u_sq * (c(-768.0) + u_sq * (c(320.0) - c(175.0) * u_sq)));
+    let big_b = u_sq / c(1024.0)
+        * (c(256.0) + u_sq * (c(-128.0) + u_sq * (c(74.0) - c(47.0) * u_sq)));
+    (big_a, big_b)
+}
+
+fn sigma_correction<T: Float>(
+    big_b: T,
+    sin_sigma: T,
+    cos_sigma: T,
+    cos_2sigma_m: T,
+) -> T {
+    let c = |v: f64| T::from(v).unwrap();
+    let cos_sq_2sigma_m = cos_2sigma_m * cos_2sigma_m;
+    big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / c(4.0)
+                * (cos_sigma * (c(-1.0) + c(2.0) * cos_sq_2sigma_m)
+                    - big_b / c(6.0)
+                        * cos_2sigma_m
+                        * (c(-3.0) + c(4.0) * sin_sigma * sin_sigma)
+                        * (c(-3.0) + c(4.0) * cos_sq_2sigma_m)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo_types::point;
+
+    // Flinders Peak to Buninyong, from Vincenty's original paper
+    fn flinders_peak() -> Point<f64> {
+        point!(
+            x: 144.0 + 25.0 / 60.0 + 29.52440 / 3600.0,
+            y: -(37.0 + 57.0 / 60.0 + 3.72030 / 3600.0)
+        )
+    }
+
+    fn buninyong() -> Point<f64> {
+        point!(
+            x: 143.0 + 55.0 / 60.0 + 35.38390 / 3600.0,
+            y: -(37.0 + 39.0 / 60.0 + 10.15610 / 3600.0)
+        )
+    }
+
+    #[test]
+    fn test_geodesic_inverse() {
+        let geodesic = Geodesic::new(DistanceUnit::Meters);
+        let inverse = geodesic
+            .inverse(&flinders_peak(), &buninyong())
+            .expect("Converges");
+
+        assert!((inverse.distance() - 54972.271).abs() < 1e-3);
+        let initial = 306.0 + 52.0 / 60.0 + 5.37 / 3600.0 - 360.0;
+        assert!((inverse.initial_bearing() - initial).abs() < 1e-5);
+        // the paper gives the reverse azimuth at Buninyong
+        let final_bearing = 127.0 + 10.0 / 60.0 + 25.07 / 3600.0 - 180.0;
+        assert!((inverse.final_bearing() - final_bearing).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_geodesic_direct() {
+        let geodesic = Geodesic::new(DistanceUnit::Meters);
+        let bearing = 306.0 + 52.0 / 60.0 + 5.37 / 3600.0;
+        let actual = geodesic
+            .destination(&flinders_peak(), 54972.271, bearing)
+            .expect("Converges");
+
+        assert!((actual.x() - buninyong().x()).abs() < 1e-7);
+        assert!((actual.y() - buninyong().y()).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_geodesic_direct_not_finite() {
+        let geodesic = Geodesic::new(DistanceUnit::Meters);
+
+        for &dist in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+            assert!(
+                geodesic.destination(&flinders_peak(), dist, 30.0).is_none()
+            );
+        }
+    }
+
+    #[test]
+    fn test_geodesic_units() {
+        let meters = Geodesic::new(DistanceUnit::Meters);
+        let miles = Geodesic::new(DistanceUnit::Miles);
+
+        let d = meters.distance(&flinders_peak(), &buninyong()).unwrap();
+        let d2 = miles.distance(&flinders_peak(), &buninyong()).unwrap();
+        assert!((d / d2 - 1609.344).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geodesic_coincident_and_antipodal() {
+        let geodesic = Geodesic::new(DistanceUnit::Meters);
+
+        let p = flinders_peak();
+        assert_eq!(geodesic.distance(&p, &p), Some(0.0));
+
+        let a = point!(x: 0.0, y: 0.0);
+        let b = point!(x: 179.7, y: 0.5);
+        assert!(geodesic.distance(&a, &b).is_none());
+    }
+}
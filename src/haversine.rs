@@ -0,0 +1,142 @@
+use crate::{wrap_longitude, DistanceUnit, Ellipsoid, LngLat, Metric};
+use geo_types::Point;
+use num_traits::Float;
+use std::fmt;
+
+/// Great-circle measurements on a sphere, using the haversine formula
+///
+/// Slower than [`CheapRuler`](crate::CheapRuler), but its error does not grow
+/// with distance. Measured against the WGS84 ellipsoid, distances are within
+/// about 0.5% anywhere on Earth.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Haversine<T>
+where
+    T: Float + fmt::Debug,
+{
+    radius: T,
+}
+
+impl<T> Haversine<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a haversine model on a sphere with the mean radius of the
+    /// Earth, expressing distances in the given unit
+    ///
+    /// # Arguments
+    ///
+    /// * `distance_unit` - Unit to express distances in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{DistanceUnit, Haversine, Metric};
+    /// let haversine = Haversine::new(DistanceUnit::Kilometers);
+    /// let dist: f64 =
+    ///     haversine.distance(&(-0.1278, 51.5074), &(2.3522, 48.8566));
+    /// assert!((dist - 343.56).abs() < 0.01);
+    /// ```
+    pub fn new(distance_unit: DistanceUnit) -> Self {
+        let radius = Ellipsoid::SPHERE.semi_major_axis_as::<T>()
+            * distance_unit.conversion_factor_kilometers();
+        Self::with_radius(radius)
+    }
+
+    /// Creates a haversine model on a sphere with the given radius. Distances
+    /// are expressed in the unit of the radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Radius of the sphere
+    pub fn with_radius(radius: T) -> Self {
+        Self { radius }
+    }
+
+    /// Returns the radius of the sphere
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+}
+
+impl<T> Metric<T> for Haversine<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn distance<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let two = T::from(2.0).unwrap();
+        let lat1 = a.lat().to_radians();
+        let lat2 = b.lat().to_radians();
+        let dlat = lat2 - lat1;
+        let dlon = (b.lng() - a.lng()).to_radians();
+
+        let h = (dlat / two).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (dlon / two).sin().powi(2);
+        two * self.radius * h.min(T::one()).sqrt().asin()
+    }
+
+    fn bearing<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let lat1 = a.lat().to_radians();
+        let lat2 = b.lat().to_radians();
+        let dlon = (b.lng() - a.lng()).to_radians();
+
+        let y = dlon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+        y.atan2(x).to_degrees()
+    }
+
+    fn destination<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        dist: T,
+        bearing: T,
+    ) -> Point<T> {
+        let delta = dist / self.radius;
+        let theta = bearing.to_radians();
+        let lat1 = origin.lat().to_radians();
+
+        let sin_lat2 =
+            lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * theta.cos();
+        let lat2 = sin_lat2.max(-T::one()).min(T::one()).asin();
+        let dlon = (theta.sin() * delta.sin() * lat1.cos())
+            .atan2(delta.cos() - lat1.sin() * sin_lat2);
+
+        let x =
+            wrap_longitude(origin.lng() + dlon.to_degrees(), &[origin.lng()]);
+        (x, lat2.to_degrees()).into()
+    }
+
+    fn offset<P: LngLat<T>>(&self, origin: &P, dx: T, dy: T) -> Point<T> {
+        let dist = dx.hypot(dy);
+        let bearing = dx.atan2(dy).to_degrees();
+        self.destination(origin, dist, bearing)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo_types::point;
+
+    #[test]
+    fn test_haversine_round_trip() {
+        let haversine = Haversine::new(DistanceUnit::Meters);
+        let a = point!(x: 179.9, y: 32.8);
+
+        let b = haversine.destination(&a, 50_000.0, 60.0);
+        assert!(b.x() < -179.0);
+        assert!((haversine.distance(&a, &b) - 50_000.0).abs() < 1e-6);
+        assert!((haversine.bearing(&a, &b) - 60.0).abs() < 1e-9);
+
+        let c = haversine.offset(&a, 3000.0, 4000.0);
+        assert!((haversine.distance(&a, &c) - 5000.0).abs() < 1e-6);
+    }
+}
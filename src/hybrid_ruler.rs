@@ -0,0 +1,313 @@
+use crate::{CheapRuler, DistanceUnit, Ellipsoid, Geodesic, LngLat};
+use geo_types::{LineString, Point};
+use num_traits::Float;
+use std::fmt;
+
+/// Method used to make a [`HybridRuler`] measurement
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Method {
+    /// The cheap ruler approximation
+    Cheap,
+    /// The exact ellipsoidal geodesic
+    Exact,
+}
+
+/// Result of a [`HybridRuler`] measurement, along with the method used to
+/// calculate it
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Measurement<V> {
+    value: V,
+    method: Method,
+}
+
+impl<V> Measurement<V> {
+    fn new(value: V, method: Method) -> Self {
+        Self { value, method }
+    }
+
+    /// Returns the measured value
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Returns the method used for the measurement
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Consumes the measurement, returning the measured value
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+/// A ruler that uses the cheap approximations for spans shorter than a
+/// threshold, and exact ellipsoidal geodesics for longer spans.
+///
+/// The span of a measurement is its approximate distance, as measured by the
+/// cheap ruler. If the exact solution fails to converge, which happens for
+/// nearly antipodal points or distances that aren't finite, the cheap result
+/// is returned instead.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct HybridRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    ruler: CheapRuler<T>,
+    geodesic: Geodesic<T>,
+    threshold: T,
+}
+
+impl<T> HybridRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a hybrid ruler for the given latitude
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude for the cheap ruler
+    /// * `distance_unit` - Unit to express distances in
+    /// * `threshold` - Shortest span to measure exactly, in `distance_unit`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{DistanceUnit, HybridRuler, Method};
+    /// let hr = HybridRuler::new(44.7192003, DistanceUnit::Meters, 10_000.0);
+    /// let dist =
+    ///     hr.distance(&(14.8901816, 44.7209699), &[14.8905188, 44.7209699]);
+    /// assert_eq!(dist.method(), Method::Cheap);
+    /// ```
+    pub fn new(latitude: T, distance_unit: DistanceUnit, threshold: T) -> Self {
+        Self::with_ellipsoid(
+            latitude,
+            distance_unit,
+            threshold,
+            Ellipsoid::WGS84,
+        )
+    }
+
+    /// Creates a hybrid ruler for the given latitude, with both methods using
+    /// the given ellipsoid
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude for the cheap ruler
+    /// * `distance_unit` - Unit to express distances in
+    /// * `threshold` - Shortest span to measure exactly, in `distance_unit`
+    /// * `ellipsoid` - Ellipsoid modelling the shape of the Earth
+    pub fn with_ellipsoid(
+        latitude: T,
+        distance_unit: DistanceUnit,
+        threshold: T,
+        ellipsoid: Ellipsoid,
+    ) -> Self {
+        Self {
+            ruler: CheapRuler::with_ellipsoid(
+                latitude,
+                distance_unit,
+                ellipsoid,
+            ),
+            geodesic: Geodesic::with_ellipsoid(distance_unit, ellipsoid),
+            threshold,
+        }
+    }
+
+    /// Returns the cheap ruler used for short spans
+    pub fn ruler(&self) -> &CheapRuler<T> {
+        &self.ruler
+    }
+
+    /// Returns the geodesic used for long spans
+    pub fn geodesic(&self) -> &Geodesic<T> {
+        &self.geodesic
+    }
+
+    /// Returns the shortest span that is measured exactly
+    pub fn threshold(&self) -> T {
+        self.threshold
+    }
+
+    /// Calculates the distance between two geographical points
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    pub fn distance<A, B>(&self, a: &A, b: &B) -> Measurement<T>
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let cheap = self.ruler.distance(a, b);
+        if cheap < self.threshold {
+            return Measurement::new(cheap, Method::Cheap);
+        }
+
+        match self.geodesic.distance(a, b) {
+            Some(exact) => Measurement::new(exact, Method::Exact),
+            None => Measurement::new(cheap, Method::Cheap),
+        }
+    }
+
+    /// Returns the bearing between two points in angles
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    pub fn bearing<A, B>(&self, a: &A, b: &B) -> Measurement<T>
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        if self.ruler.distance(a, b) < self.threshold {
+            return Measurement::new(self.ruler.bearing(a, b), Method::Cheap);
+        }
+
+        match self.geodesic.bearing(a, b) {
+            Some(exact) => Measurement::new(exact, Method::Exact),
+            None => Measurement::new(self.ruler.bearing(a, b), Method::Cheap),
+        }
+    }
+
+    /// Returns a new point given distance and bearing from the starting point
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing` - bearing
+    pub fn destination<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        dist: T,
+        bearing: T,
+    ) -> Measurement<Point<T>> {
+        let cheap = self.ruler.destination(origin, dist, bearing);
+        if dist.abs() < self.threshold {
+            return Measurement::new(cheap, Method::Cheap);
+        }
+
+        match self.geodesic.destination(origin, dist, bearing) {
+            Some(exact) => Measurement::new(exact, Method::Exact),
+            None => Measurement::new(cheap, Method::Cheap),
+        }
+    }
+
+    /// Given a line (an array of points), returns the total line distance.
+    /// Lines whose approximate length reaches the threshold are measured
+    /// exactly, segment by segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - line of points
+    pub fn line_distance(&self, points: &LineString<T>) -> Measurement<T> {
+        let cheap = self.ruler.line_distance(points);
+        if cheap < self.threshold {
+            return Measurement::new(cheap, Method::Cheap);
+        }
+
+        points
+            .lines()
+            .try_fold(T::zero(), |acc, line| {
+                self.geodesic
+                    .distance(&line.start, &line.end)
+                    .map(|d| acc + d)
+            })
+            .map_or(Measurement::new(cheap, Method::Cheap), |exact| {
+                Measurement::new(exact, Method::Exact)
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo_types::{line_string, point};
+
+    fn hybrid_ruler() -> HybridRuler<f64> {
+        HybridRuler::new(45.0, DistanceUnit::Kilometers, 100.0)
+    }
+
+    #[test]
+    fn test_hybrid_ruler_distance() {
+        let ruler = hybrid_ruler();
+        let a = point!(x: 15.0, y: 45.0);
+
+        let near = point!(x: 15.1, y: 45.1);
+        let actual = ruler.distance(&a, &near);
+        assert_eq!(actual.method(), Method::Cheap);
+        assert_eq!(*actual.value(), ruler.ruler().distance(&a, &near));
+
+        let far = point!(x: 25.0, y: 55.0);
+        let actual = ruler.distance(&a, &far);
+        assert_eq!(actual.method(), Method::Exact);
+        assert_eq!(
+            actual.into_value(),
+            ruler.geodesic().distance(&a, &far).unwrap()
+        );
+
+        // positions can be given as tuples and arrays, like with CheapRuler
+        let actual = ruler.distance(&(15.0, 45.0), &[25.0, 55.0]);
+        assert_eq!(actual, ruler.distance(&a, &far));
+    }
+
+    #[test]
+    fn test_hybrid_ruler_bearing_and_destination() {
+        let ruler = hybrid_ruler();
+        let a = point!(x: 15.0, y: 45.0);
+        let far = point!(x: 25.0, y: 55.0);
+
+        let bearing = ruler.bearing(&a, &far);
+        assert_eq!(bearing.method(), Method::Exact);
+
+        let distance = ruler.distance(&a, &far).into_value();
+        let destination = ruler.destination(&a, distance, *bearing.value());
+        assert_eq!(destination.method(), Method::Exact);
+        assert!((destination.value().x() - far.x()).abs() < 1e-9);
+        assert!((destination.value().y() - far.y()).abs() < 1e-9);
+
+        let destination = ruler.destination(&a, 1.0, 90.0);
+        assert_eq!(destination.method(), Method::Cheap);
+    }
+
+    #[test]
+    fn test_hybrid_ruler_line_distance() {
+        let ruler = hybrid_ruler();
+
+        let short = line_string![(x: 15.0, y: 45.0), (x: 15.1, y: 45.0)];
+        assert_eq!(ruler.line_distance(&short).method(), Method::Cheap);
+
+        let long = line_string![
+            (x: 15.0, y: 45.0),
+            (x: 15.5, y: 45.5),
+            (x: 16.0, y: 46.0),
+        ];
+        let actual = ruler.line_distance(&long);
+        assert_eq!(actual.method(), Method::Exact);
+        assert!((actual.into_value() - 135.9).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_hybrid_ruler_antipodal_fallback() {
+        let ruler = hybrid_ruler();
+        let a = point!(x: 0.0, y: 0.0);
+        let b = point!(x: 179.7, y: 0.5);
+
+        assert_eq!(ruler.distance(&a, &b).method(), Method::Cheap);
+    }
+
+    #[test]
+    fn test_hybrid_ruler_destination_not_finite() {
+        let ruler = hybrid_ruler();
+        let a = point!(x: 15.0, y: 45.0);
+
+        for &dist in [f64::NAN, f64::INFINITY].iter() {
+            let destination = ruler.destination(&a, dist, 30.0);
+            assert_eq!(destination.method(), Method::Cheap);
+        }
+    }
+}
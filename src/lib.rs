@@ -2,12 +2,24 @@
 //!
 //! A collection of very fast approximations to common geodesic measurements.
 //! Useful for performance-sensitive code that measures things on a city scale.
+//! Use [`CheapRuler::error_estimate`] to check how accurate the approximations
+//! are for a given extent.
 //!
 //! This is a port of the cheap-ruler JS library and cheap-ruler-cpp C++ library
 //! into safe Rust.
 //!
 //! Note: WGS84 ellipsoid is used instead of the Clarke 1866 parameters used by
-//! the FCC formulas. See cheap-ruler-cpp#13 for more information.
+//! the FCC formulas. See cheap-ruler-cpp#13 for more information. Other
+//! ellipsoids can be selected with [`CheapRuler::with_ellipsoid`].
+//!
+//! ## Features
+//!
+//! * `simd` - Vectorizes the structure-of-arrays kernels such as
+//!   [`CheapRuler::distances_soa`] for `f32` and `f64`.
+//! * `rayon` - Adds parallel variants of bulk operations such as
+//!   `CheapRuler::par_line_distances`.
+//! * `serde` - Implements `Serialize` and `Deserialize` for result types such
+//!   as [`PointOnLine`] and [`StationOffset`].
 
 // Temporarily permit geo_types::Coordinate until geo-types 0.8
 #![allow(deprecated)]
@@ -15,24 +27,65 @@
 #[macro_use]
 extern crate geo_types;
 
-use geo_types::{Coordinate, LineString, Point, Polygon};
+use geo_types::{Coordinate, LineString, Point};
+use lng_lat::to_point;
 use num_traits::Float;
 use std::f64;
 use std::fmt;
-use std::iter;
 use std::mem;
 
+mod algorithm;
+mod densify;
 mod distance_unit;
+mod ellipsoid;
+mod error;
+mod error_estimate;
+mod geodesic;
+mod haversine;
+mod hybrid_ruler;
+mod lng_lat;
+mod local_frame;
+mod map_coords;
+mod measure;
+mod measured_line;
+mod metric;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod point_on_line;
 mod rect;
+mod ruler_cache;
+mod simd;
+mod simplify;
+mod station_offset;
+mod transform;
+mod visvalingam;
 
+pub use algorithm::{
+    CheapArea, CheapBearing, CheapDestination, CheapDistance, CheapLength,
+};
 pub use distance_unit::DistanceUnit;
-pub use point_on_line::PointOnLine;
+pub use ellipsoid::Ellipsoid;
+pub use error::Error;
+pub use error_estimate::ErrorEstimate;
+pub use geodesic::{Geodesic, GeodesicInverse};
+pub use haversine::Haversine;
+pub use hybrid_ruler::{HybridRuler, Measurement, Method};
+pub use lng_lat::LngLat;
+pub use local_frame::LocalFrame;
+pub use map_coords::CheapMapCoords;
+pub use measure::Measure;
+pub use measured_line::MeasuredLine;
+pub use metric::Metric;
+pub use point_on_line::{PointOnLine, Side};
 pub use rect::Rect;
+pub use ruler_cache::RulerCache;
+pub use simd::SimdFloat;
+pub use simplify::CheapSimplify;
+pub use station_offset::StationOffset;
+pub use visvalingam::{VwSimplified, VwTarget};
 
 const RE: f64 = 6378.137; // equatorial radius in km
 const FE: f64 = 1.0 / 298.257223563; // flattening
-const E2: f64 = FE * (2.0 - FE);
 
 /// A collection of very fast approximations to common geodesic measurements.
 /// Useful for performance-sensitive code that measures things on a city scale.
@@ -52,19 +105,108 @@ where
     T: Float + fmt::Debug,
 {
     pub fn new(latitude: T, distance_unit: DistanceUnit) -> Self {
-        let one = T::one();
-        let e2 = T::from(E2).unwrap();
+        Self::with_ellipsoid(latitude, distance_unit, Ellipsoid::WGS84)
+    }
+
+    /// Creates a ruler object for the given latitude, with the curvature of
+    /// the given ellipsoid
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude
+    /// * `distance_unit` - Unit to express distances in
+    /// * `ellipsoid` - Ellipsoid modelling the shape of the Earth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Ellipsoid};
+    /// let cr = CheapRuler::with_ellipsoid(
+    ///     44.7192003,
+    ///     DistanceUnit::Meters,
+    ///     Ellipsoid::CLARKE_1866,
+    /// );
+    /// ```
+    pub fn with_ellipsoid(
+        latitude: T,
+        distance_unit: DistanceUnit,
+        ellipsoid: Ellipsoid,
+    ) -> Self {
+        let (dkx, dky) = curvature_multipliers(
+            latitude,
+            ellipsoid.eccentricity_squared_as(),
+        );
+        Self::from_curvature_multipliers(distance_unit, &ellipsoid, dkx, dky)
+    }
+
+    /// Creates a ruler object for the given latitude, returning an error
+    /// instead of producing meaningless multipliers for invalid input
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude, within ±90 degrees
+    /// * `distance_unit` - Unit to express distances in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Error};
+    /// let cr = CheapRuler::try_new(44.7192003, DistanceUnit::Meters);
+    /// assert_eq!(cr, Ok(CheapRuler::new(44.7192003, DistanceUnit::Meters)));
+    ///
+    /// let cr = CheapRuler::try_new(f64::NAN, DistanceUnit::Meters);
+    /// assert!(matches!(cr, Err(Error::InvalidLatitude(_))));
+    /// ```
+    pub fn try_new(
+        latitude: T,
+        distance_unit: DistanceUnit,
+    ) -> Result<Self, Error> {
+        Self::try_with_ellipsoid(latitude, distance_unit, Ellipsoid::WGS84)
+    }
+
+    /// Creates a ruler object for the given latitude, with the curvature of
+    /// the given ellipsoid, returning an error for invalid input
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude, within ±90 degrees
+    /// * `distance_unit` - Unit to express distances in
+    /// * `ellipsoid` - Ellipsoid modelling the shape of the Earth
+    pub fn try_with_ellipsoid(
+        latitude: T,
+        distance_unit: DistanceUnit,
+        ellipsoid: Ellipsoid,
+    ) -> Result<Self, Error> {
+        let ninety: T = convert(90.0)?;
+        if !latitude.is_finite() || latitude.abs() > ninety {
+            let latitude = latitude.to_f64().ok_or(Error::NumericConversion)?;
+            return Err(Error::InvalidLatitude(latitude));
+        }
 
-        // Curvature formulas from https://en.wikipedia.org/wiki/Earth_radius#Meridional
-        let coslat = latitude.to_radians().cos();
-        let w2 = one / (one - e2 * (one - coslat * coslat));
-        let w = w2.sqrt();
+        let e2 = convert(ellipsoid.eccentricity_squared())?;
+        let (dkx, dky) = curvature_multipliers(latitude, e2);
+        let (kx, ky) = calculate_multipliers(
+            convert(distance_unit.kilometers_factor())?,
+            convert(ellipsoid.semi_major_axis())?,
+            dkx,
+            dky,
+        );
 
-        // multipliers for converting longitude and latitude degrees into distance
-        let dkx = w * coslat; // based on normal radius of curvature
-        let dky = w * w2 * (one - e2); // based on meridonal radius of curvature
+        Ok(Self { kx, ky })
+    }
 
-        let (kx, ky) = calculate_multipliers(distance_unit, dkx, dky);
+    pub(crate) fn from_curvature_multipliers(
+        distance_unit: DistanceUnit,
+        ellipsoid: &Ellipsoid,
+        dkx: T,
+        dky: T,
+    ) -> Self {
+        let (kx, ky) = calculate_multipliers(
+            distance_unit.conversion_factor_kilometers(),
+            ellipsoid.semi_major_axis_as(),
+            dkx,
+            dky,
+        );
 
         Self { kx, ky }
     }
@@ -87,14 +229,42 @@ where
     pub fn from_tile(y: u32, z: u32, distance_unit: DistanceUnit) -> Self {
         assert!(z < 32);
 
-        let n = T::from(f64::consts::PI).unwrap()
-            * (T::one()
-                - T::from(2.0).unwrap()
-                    * (T::from(y).unwrap() + T::from(0.5).unwrap())
-                    / T::from(1u32 << z).unwrap());
-        let latitude = n.sinh().atan().to_degrees();
+        Self::new(tile_latitude(y, z).unwrap(), distance_unit)
+    }
 
-        Self::new(latitude, distance_unit)
+    /// Creates a ruler object from tile coordinates (y and z), returning an
+    /// error for a zoom level or tile row that does not exist
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - y
+    /// * `z` - z
+    /// * `distance_unit` - Unit to express distances in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, Error};
+    /// let cr = CheapRuler::<f64>::try_from_tile(1567, 12, DistanceUnit::Meters);
+    /// assert!(cr.is_ok());
+    ///
+    /// let cr = CheapRuler::<f64>::try_from_tile(0, 32, DistanceUnit::Meters);
+    /// assert_eq!(cr, Err(Error::InvalidZoom(32)));
+    /// ```
+    pub fn try_from_tile(
+        y: u32,
+        z: u32,
+        distance_unit: DistanceUnit,
+    ) -> Result<Self, Error> {
+        if z >= 32 {
+            return Err(Error::InvalidZoom(z));
+        }
+        if y >= 1u32 << z {
+            return Err(Error::TileOutOfRange { y, z });
+        }
+
+        let latitude = tile_latitude(y, z).ok_or(Error::NumericConversion)?;
+        Self::try_new(latitude, distance_unit)
     }
 
     /// Calculates the square of the approximate distance between two
@@ -104,9 +274,13 @@ where
     ///
     /// * `a` - First point
     /// * `b` - Second point
-    pub fn square_distance(&self, a: &Point<T>, b: &Point<T>) -> T {
-        let dx = long_diff(a.x(), b.x()) * self.kx;
-        let dy = (a.y() - b.y()) * self.ky;
+    pub fn square_distance<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let dx = long_diff(a.lng(), b.lng()) * self.kx;
+        let dy = (a.lat() - b.lat()) * self.ky;
         dx.powi(2) + dy.powi(2)
     }
 
@@ -123,15 +297,113 @@ where
     /// use cheap_ruler::{CheapRuler, DistanceUnit};
     /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
     /// let dist = cr.distance(
-    ///   &(14.8901816, 44.7209699).into(),
-    ///   &(14.8905188, 44.7209699).into()
+    ///   &(14.8901816, 44.7209699),
+    ///   &(14.8905188, 44.7209699)
     /// );
     /// assert!(dist < 38.0);
     /// ```
-    pub fn distance(&self, a: &Point<T>, b: &Point<T>) -> T {
+    pub fn distance<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
         self.square_distance(a, b).sqrt()
     }
 
+    /// Calculates the approximate distances from an origin point to each of
+    /// the given points, writing them into `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin point
+    /// * `points` - Points to measure the distance to
+    /// * `out` - Output buffer, one distance per point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is not the same length as `points`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::Point;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let origin = Point::new(14.8901816, 44.7209699);
+    /// let points = [
+    ///     Point::new(14.8905188, 44.7209699),
+    ///     Point::new(14.8901816, 44.7219699),
+    /// ];
+    /// let mut distances = [0.0; 2];
+    /// cr.distances(&origin, &points, &mut distances);
+    /// assert_eq!(distances[0], cr.distance(&origin, &points[0]));
+    /// ```
+    pub fn distances<O, P>(&self, origin: &O, points: &[P], out: &mut [T])
+    where
+        O: LngLat<T>,
+        P: LngLat<T>,
+    {
+        assert_eq!(points.len(), out.len());
+
+        for (p, d) in points.iter().zip(out.iter_mut()) {
+            *d = self.distance(origin, p);
+        }
+    }
+
+    /// Calculates the approximate distances between points at the same index
+    /// of two slices, writing them into `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First points
+    /// * `b` - Second points
+    /// * `out` - Output buffer, one distance per pair of points
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a`, `b` and `out` are not all the same length.
+    pub fn pairwise_distances<A, B>(&self, a: &[A], b: &[B], out: &mut [T])
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        assert_eq!(a.len(), b.len());
+        assert_eq!(a.len(), out.len());
+
+        for ((a, b), d) in a.iter().zip(b).zip(out.iter_mut()) {
+            *d = self.distance(a, b);
+        }
+    }
+
+    /// Calculates the approximate distances between every point in `from` and
+    /// every point in `to`, writing them into `out` as a row-major matrix with
+    /// one row per point in `from`
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Points for the matrix rows
+    /// * `to` - Points for the matrix columns
+    /// * `out` - Output buffer of `from.len() * to.len()` distances
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is not `from.len() * to.len()` long.
+    pub fn distance_matrix<A, B>(&self, from: &[A], to: &[B], out: &mut [T])
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        assert_eq!(from.len() * to.len(), out.len());
+
+        if to.is_empty() {
+            return;
+        }
+
+        for (a, row) in from.iter().zip(out.chunks_exact_mut(to.len())) {
+            self.distances(a, to, row);
+        }
+    }
+
     /// Returns the bearing between two points in angles
     ///
     /// # Arguments
@@ -145,14 +417,18 @@ where
     /// use cheap_ruler::{CheapRuler, DistanceUnit};
     /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
     /// let bearing = cr.bearing(
-    ///   &(14.8901816, 44.7209699).into(),
-    ///   &(14.8905188, 44.7209699).into()
+    ///   &(14.8901816, 44.7209699),
+    ///   &(14.8905188, 44.7209699)
     /// );
     /// assert_eq!(bearing, 90.0);
     /// ```
-    pub fn bearing(&self, a: &Point<T>, b: &Point<T>) -> T {
-        let dx = long_diff(b.x(), a.x()) * self.kx;
-        let dy = (b.y() - a.y()) * self.ky;
+    pub fn bearing<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        let dx = long_diff(b.lng(), a.lng()) * self.kx;
+        let dy = (b.lat() - a.lat()) * self.ky;
 
         dx.atan2(dy).to_degrees()
     }
@@ -170,18 +446,18 @@ where
     /// ```
     /// use cheap_ruler::{CheapRuler, DistanceUnit};
     /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
-    /// let p1 = (14.8901816, 44.7209699).into();
-    /// let p2 = (14.8905188, 44.7209699).into();
+    /// let p1 = (14.8901816, 44.7209699);
+    /// let p2 = (14.8905188, 44.7209699);
     /// let dist = cr.distance(&p1, &p2);
     /// let bearing = cr.bearing(&p1, &p2);
     /// let destination = cr.destination(&p1, dist, bearing);
     ///
-    /// assert_eq!(destination.x(), p2.x());
-    /// assert_eq!(destination.y(), p2.y());
+    /// assert_eq!(destination.x(), p2.0);
+    /// assert_eq!(destination.y(), p2.1);
     /// ```
-    pub fn destination(
+    pub fn destination<P: LngLat<T>>(
         &self,
-        origin: &Point<T>,
+        origin: &P,
         dist: T,
         bearing: T,
     ) -> Point<T> {
@@ -197,8 +473,9 @@ where
     /// * `origin` - point
     /// * `dx` - easting
     /// * `dy` - northing
-    pub fn offset(&self, origin: &Point<T>, dx: T, dy: T) -> Point<T> {
-        (origin.x() + dx / self.kx, origin.y() + dy / self.ky).into()
+    pub fn offset<P: LngLat<T>>(&self, origin: &P, dx: T, dy: T) -> Point<T> {
+        let x = wrap_longitude(origin.lng() + dx / self.kx, &[origin.lng()]);
+        (x, origin.lat() + dy / self.ky).into()
     }
 
     /// Given a line (an array of points), returns the total line distance.
@@ -222,34 +499,170 @@ where
     /// let length = cr.line_distance(&line_string);
     /// ```
     pub fn line_distance(&self, points: &LineString<T>) -> T {
-        let line_iter = points.0.iter().copied();
-
-        let left = iter::once(None).chain(line_iter.clone().map(Some));
-        left.zip(line_iter)
-            .map(|(a, b)| match a {
-                Some(a) => self.distance(&a.into(), &b.into()),
-                None => T::zero(),
-            })
-            // avoided using Iterator's sum() so that we don't have to require T
-            // to implement std::iter::Sum.
-            .fold(T::zero(), |acc, x| acc + x)
-    }
-
-    /// Given a polygon returns the area
-    ///
-    /// * `polygon` - Polygon
-    pub fn area(&self, polygon: &Polygon<T>) -> T {
-        let exterior_sum =
-            sum_area(&polygon.exterior().points().collect::<Vec<Point<T>>>());
-        let interiors_sum = polygon
-            .interiors()
-            .iter()
-            .map(|interior| {
-                sum_area(&interior.points().collect::<Vec<Point<T>>>())
-            })
+        self.line_distance_coords(&points.0)
+    }
+
+    /// Given a sequence of coordinates, returns the total line distance
+    /// without allocating
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - Slice or iterator of coordinates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let buffer = [[-67.031, 50.458], [-67.031, 50.534], [-66.929, 50.534]];
+    /// let length = cr.line_distance_coords(&buffer);
+    /// ```
+    pub fn line_distance_coords<I>(&self, coords: I) -> T
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+    {
+        let mut coords = coords.into_iter();
+        let mut prev = match coords.next() {
+            Some(c) => c,
+            None => return T::zero(),
+        };
+
+        // avoided using Iterator's sum() so that we don't have to require T
+        // to implement std::iter::Sum.
+        coords.fold(T::zero(), |acc, c| {
+            let d = self.distance(&prev, &c);
+            prev = c;
+            acc + d
+        })
+    }
+
+    /// Given a geometry returns the total area of its polygons. Holes are
+    /// subtracted from the exterior ring regardless of how the rings are
+    /// wound, and geometries without polygons have no area.
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Polygon, or any other geometry implementing [`Measure`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::{polygon, Geometry};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let polygon = polygon![
+    ///     (x: 14.89, y: 44.72),
+    ///     (x: 14.90, y: 44.72),
+    ///     (x: 14.90, y: 44.73),
+    /// ];
+    /// let area = cr.area(&polygon);
+    /// assert_eq!(cr.area(&Geometry::Polygon(polygon)), area);
+    /// ```
+    pub fn area<G: Measure<T> + ?Sized>(&self, geometry: &G) -> T {
+        geometry.area(self)
+    }
+
+    /// Given a geometry returns the total length of its lines. Geometries
+    /// without lines, such as points and polygons, have no length.
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Line string, or any other geometry implementing
+    ///   [`Measure`]
+    pub fn length<G: Measure<T> + ?Sized>(&self, geometry: &G) -> T {
+        geometry.length(self)
+    }
+
+    /// Given a geometry returns the total length of its polygon rings,
+    /// including holes. Geometries without polygons have no perimeter.
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Polygon, or any other geometry implementing [`Measure`]
+    pub fn perimeter<G: Measure<T> + ?Sized>(&self, geometry: &G) -> T {
+        geometry.perimeter(self)
+    }
+
+    /// Given a ring returns its signed area, which is positive if the ring is
+    /// wound counter-clockwise and negative if it is wound clockwise
+    ///
+    /// # Arguments
+    ///
+    /// * `ring` - Closed line string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::line_string;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let ring = line_string![
+    ///     (x: 14.89, y: 44.72),
+    ///     (x: 14.90, y: 44.72),
+    ///     (x: 14.90, y: 44.73),
+    ///     (x: 14.89, y: 44.72),
+    /// ];
+    /// assert!(cr.signed_area(&ring) > 0.0);
+    /// ```
+    pub fn signed_area(&self, ring: &LineString<T>) -> T {
+        self.signed_area_coords(&ring.0)
+    }
+
+    /// Given a ring as a sequence of coordinates returns its signed area
+    /// without allocating. The area is positive if the ring is wound
+    /// counter-clockwise and negative if it is wound clockwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `ring` - Slice or iterator of coordinates of a closed ring
+    pub fn signed_area_coords<I>(&self, ring: I) -> T
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+    {
+        let sum = sum_area(ring);
+        (-sum / T::from(2.0).unwrap()) * self.kx * self.ky
+    }
+
+    /// Given the rings of a polygon as sequences of coordinates returns its
+    /// area without allocating. Holes are subtracted from the exterior ring
+    /// regardless of how the rings are wound.
+    ///
+    /// # Arguments
+    ///
+    /// * `exterior` - Slice or iterator of coordinates of the exterior ring
+    /// * `interiors` - Iterator of the interior rings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::{coord, Coordinate};
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let exterior = [
+    ///     coord!(x: 14.89, y: 44.72),
+    ///     coord!(x: 14.90, y: 44.72),
+    ///     coord!(x: 14.90, y: 44.73),
+    ///     coord!(x: 14.89, y: 44.72),
+    /// ];
+    /// let area = cr.area_coords(&exterior, std::iter::empty::<&[Coordinate]>());
+    /// assert_eq!(area, cr.signed_area_coords(&exterior));
+    /// ```
+    pub fn area_coords<I, J>(&self, exterior: I, interiors: J) -> T
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+        J: IntoIterator,
+        J::Item: IntoIterator,
+        <J::Item as IntoIterator>::Item: LngLat<T>,
+    {
+        let exterior_area = self.signed_area_coords(exterior).abs();
+        let interiors_area = interiors
+            .into_iter()
+            .map(|interior| self.signed_area_coords(interior).abs())
             .fold(T::zero(), |acc, x| acc + x);
-        let sum = exterior_sum - interiors_sum;
-        (sum.abs() / T::from(2.0).unwrap()) * self.kx * self.ky
+        exterior_area - interiors_area
     }
 
     /// Returns the point at a specified distance along the line
@@ -259,27 +672,180 @@ where
     /// * `line` - Line
     /// * `dist` - Distance along the line
     pub fn along(&self, line: &LineString<T>, dist: T) -> Option<Point<T>> {
-        let line_len = line.0.len();
-        if line_len == 0 {
-            return None;
-        }
+        self.along_coords(&line.0, dist)
+    }
+
+    /// Returns the point at a specified distance along a sequence of
+    /// coordinates, without allocating
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - Slice or iterator of coordinates
+    /// * `dist` - Distance along the line
+    pub fn along_coords<I>(&self, coords: I, dist: T) -> Option<Point<T>>
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+    {
+        let mut coords = coords.into_iter();
+        let mut p0 = to_point(&coords.next()?);
 
         if dist <= T::zero() {
-            return Some(line[0].into());
+            return Some(p0);
         }
 
-        let last_index = line_len - 1;
         let mut sum = T::zero();
-        for i in 0..last_index {
-            let p0 = &line[i].into();
-            let p1 = &line[i + 1].into();
-            let d = self.distance(p0, p1);
+        for c in coords {
+            let p1 = to_point(&c);
+            let d = self.distance(&p0, &p1);
             sum = sum + d;
             if sum > dist {
-                return Some(interpolate(p0, p1, (dist - (sum - d)) / d));
+                return Some(interpolate(&p0, &p1, (dist - (sum - d)) / d));
             }
+            p0 = p1;
         }
-        Some(line[last_index].into())
+        Some(p0)
+    }
+
+    /// Returns the point at a specified distance along the line, along with
+    /// the start index of its segment and its position on the segment. This
+    /// is the inverse of [`CheapRuler::distance_along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `dist` - Distance along the line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let pol = cr.locate_along(&line_string, 10_000.0).unwrap();
+    /// assert_eq!(pol.index(), 1);
+    ///
+    /// let station = cr.distance_along(&line_string, &pol.point()).unwrap();
+    /// assert!((station - 10_000.0).abs() < 1e-6);
+    /// ```
+    pub fn locate_along(
+        &self,
+        line: &LineString<T>,
+        dist: T,
+    ) -> Option<PointOnLine<T>> {
+        self.locate_along_coords(&line.0, dist)
+    }
+
+    /// Returns the point at a specified distance along a sequence of
+    /// coordinates, along with the start index of its segment and its
+    /// position on the segment, without allocating
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - Slice or iterator of coordinates
+    /// * `dist` - Distance along the line
+    pub fn locate_along_coords<I>(
+        &self,
+        coords: I,
+        dist: T,
+    ) -> Option<PointOnLine<T>>
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+    {
+        let zero = T::zero();
+        let mut coords = coords.into_iter();
+        let mut p0 = to_point(&coords.next()?);
+
+        if dist <= zero {
+            // the first segment with a length gives the direction
+            let (index, bearing) = coords
+                .map(|c| to_point(&c))
+                .enumerate()
+                .find(|(_, p1)| self.distance(&p0, p1) > zero)
+                .map_or((0, zero), |(i, p1)| (i, self.bearing(&p0, &p1)));
+            return Some(PointOnLine::new(
+                p0,
+                index,
+                zero,
+                zero,
+                zero,
+                bearing,
+                Side::On,
+            ));
+        }
+
+        let mut sum = zero;
+        let mut last = None;
+        for (i, c) in coords.enumerate() {
+            let p1 = to_point(&c);
+            let d = self.distance(&p0, &p1);
+            sum = sum + d;
+            if sum > dist {
+                let t = (dist - (sum - d)) / d;
+                let point = interpolate(&p0, &p1, t);
+                let bearing = self.bearing(&p0, &p1);
+                return Some(PointOnLine::new(
+                    point,
+                    i,
+                    t,
+                    zero,
+                    dist,
+                    bearing,
+                    Side::On,
+                ));
+            }
+            if d > zero {
+                last = Some((i, p0));
+            }
+            p0 = p1;
+        }
+
+        Some(match last {
+            Some((i, prev)) => {
+                let bearing = self.bearing(&prev, &p0);
+                PointOnLine::new(p0, i, T::one(), zero, sum, bearing, Side::On)
+            }
+            None => PointOnLine::new(p0, 0, zero, zero, zero, zero, Side::On),
+        })
+    }
+
+    /// Returns the distance along the line to the closest point on the line
+    /// from the given point. This is the inverse of
+    /// [`CheapRuler::locate_along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `point` - Point to measure the distance along the line for
+    pub fn distance_along<P: LngLat<T>>(
+        &self,
+        line: &LineString<T>,
+        point: &P,
+    ) -> Option<T> {
+        self.distance_along_coords(&line.0, point)
+    }
+
+    /// Returns the distance along a sequence of coordinates to the closest
+    /// point on the line from the given point, without allocating
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - Slice or iterator of coordinates
+    /// * `point` - Point to measure the distance along the line for
+    pub fn distance_along_coords<I, P>(&self, coords: I, point: &P) -> Option<T>
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+        P: LngLat<T>,
+    {
+        self.point_on_line_coords(coords, point)
+            .map(|pol| pol.along())
     }
 
     /// Returns the shortest distance between a point and a line segment given
@@ -290,95 +856,165 @@ where
     /// * `p` - Point to calculate the distance from
     /// * `start` - Start point of line segment
     /// * `end` - End point of line segment
-    pub fn point_to_segment_distance(
+    pub fn point_to_segment_distance<P, S>(
         &self,
-        p: &Point<T>,
-        start: &Point<T>,
-        end: &Point<T>,
-    ) -> T {
+        p: &P,
+        start: &S,
+        end: &S,
+    ) -> T
+    where
+        P: LngLat<T>,
+        S: LngLat<T>,
+    {
         let zero = T::zero();
-        let mut x = start.x();
-        let mut y = start.y();
-        let dx = long_diff(end.x(), x) * self.kx;
-        let dy = (end.y() - y) * self.ky;
+        let mut x = start.lng();
+        let mut y = start.lat();
+        let dx = long_diff(end.lng(), x) * self.kx;
+        let dy = (end.lat() - y) * self.ky;
 
         if dx != zero || dy != zero {
-            let t = (long_diff(p.x(), x) * self.kx * dx
-                + (p.y() - y) * self.ky * dy)
+            let t = (long_diff(p.lng(), x) * self.kx * dx
+                + (p.lat() - y) * self.ky * dy)
                 / (dx * dx + dy * dy);
             if t > T::one() {
-                x = end.x();
-                y = end.y();
+                x = end.lng();
+                y = end.lat();
             } else if t > zero {
-                x = x + (dx / self.kx) * t;
+                x = wrap_longitude(x + (dx / self.kx) * t, &[x, end.lng()]);
                 y = y + (dy / self.ky) * t;
             }
         }
         self.distance(p, &point!(x: x, y: y))
     }
 
-    /// Returns a tuple of the form (point, index, t) where point is closest
-    /// point on the line from the given point, index is the start index of the
-    /// segment with the closest point, and t is a parameter from 0 to 1 that
-    /// indicates where the closest point is on that segment
+    /// Returns the closest point on the line from the given point, along with
+    /// the start index of the segment with the closest point, a parameter t
+    /// from 0 to 1 that indicates where the closest point is on that segment,
+    /// the distances from the point and along the line to the closest point,
+    /// the bearing of the segment and the side of the line the point lies on
     ///
     /// # Arguments
     ///
     /// * `line` - Line to compare with point
     /// * `point` - Point to calculate the closest point on the line
-    pub fn point_on_line(
+    pub fn point_on_line<P: LngLat<T>>(
         &self,
         line: &LineString<T>,
-        point: &Point<T>,
+        point: &P,
     ) -> Option<PointOnLine<T>> {
+        self.point_on_line_coords(&line.0, point)
+    }
+
+    /// Returns the closest point on a sequence of coordinates from the given
+    /// point, without allocating. See [`CheapRuler::point_on_line`].
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - Slice or iterator of coordinates
+    /// * `point` - Point to calculate the closest point on the line
+    pub fn point_on_line_coords<I, P>(
+        &self,
+        coords: I,
+        point: &P,
+    ) -> Option<PointOnLine<T>>
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+        P: LngLat<T>,
+    {
         let zero = T::zero();
         let mut min_dist = T::infinity();
         let mut min_x = zero;
         let mut min_y = zero;
         let mut min_i = 0;
         let mut min_t = zero;
+        let mut min_along = zero;
+        let mut min_segment = None;
+        let mut min_zero = false;
 
-        let line_len = line.0.len();
-        if line_len == 0 {
-            return None;
-        }
+        let mut coords = coords.into_iter();
+        let mut start = to_point(&coords.next()?);
+        let mut sum = zero;
 
-        for i in 0..line_len - 1 {
+        for (i, end) in coords.enumerate() {
+            let end = to_point(&end);
             let mut t = zero;
-            let mut x = line[i].x;
-            let mut y = line[i].y;
-            let dx = long_diff(line[i + 1].x, x) * self.kx;
-            let dy = (line[i + 1].y - y) * self.ky;
-
-            if dx != zero || dy != zero {
-                t = (long_diff(point.x(), x) * self.kx * dx
-                    + (point.y() - y) * self.ky * dy)
+            let mut x = start.lng();
+            let mut y = start.lat();
+            let dx = long_diff(end.lng(), x) * self.kx;
+            let dy = (end.lat() - y) * self.ky;
+            let is_zero = dx == zero && dy == zero;
+
+            if !is_zero {
+                t = (long_diff(point.lng(), x) * self.kx * dx
+                    + (point.lat() - y) * self.ky * dy)
                     / (dx * dx + dy * dy);
 
                 if t > T::one() {
-                    x = line[i + 1].x;
-                    y = line[i + 1].y;
+                    x = end.lng();
+                    y = end.lat();
                 } else if t > zero {
-                    x = x + (dx / self.kx) * t;
+                    x = wrap_longitude(x + (dx / self.kx) * t, &[x, end.lng()]);
                     y = y + (dy / self.ky) * t;
                 }
             }
 
             let d2 = self.square_distance(point, &point!(x: x, y: y));
 
-            if d2 < min_dist {
+            // on a tie, a segment with a length gives the side and bearing
+            if d2 < min_dist || (d2 == min_dist && min_zero && !is_zero) {
                 min_dist = d2;
+                min_zero = is_zero;
                 min_x = x;
                 min_y = y;
                 min_i = i;
                 min_t = t;
+                min_along = sum;
+                min_segment = Some((start, end));
             }
+
+            sum = sum + self.distance(&start, &end);
+            start = end;
         }
 
+        let (start, end) = match min_segment {
+            Some(segment) => segment,
+            None => {
+                let distance = self.distance(point, &start);
+                return Some(PointOnLine::new(
+                    start,
+                    0,
+                    zero,
+                    distance,
+                    zero,
+                    zero,
+                    Side::On,
+                ));
+            }
+        };
+
+        let closest = point!(x: min_x, y: min_y);
+        let dx = long_diff(end.lng(), start.lng()) * self.kx;
+        let dy = (end.lat() - start.lat()) * self.ky;
+        let px = long_diff(point.lng(), start.lng()) * self.kx;
+        let py = (point.lat() - start.lat()) * self.ky;
+        let cross = dx * py - dy * px;
+        let side = if cross > zero {
+            Side::Left
+        } else if cross < zero {
+            Side::Right
+        } else {
+            Side::On
+        };
+
         Some(PointOnLine::new(
-            point!(x: min_x, y: min_y),
+            closest,
             min_i,
             T::zero().max(T::one().min(min_t)),
+            min_dist.sqrt(),
+            min_along + self.distance(&start, &closest),
+            self.bearing(&start, &end),
+            side,
         ))
     }
 
@@ -390,14 +1026,36 @@ where
     /// * `start` - Start point
     /// * `stop` - Stop point
     /// * `line` - Line string
-    pub fn line_slice(
+    pub fn line_slice<P: LngLat<T>>(
         &self,
-        start: &Point<T>,
-        stop: &Point<T>,
+        start: &P,
+        stop: &P,
         line: &LineString<T>,
     ) -> LineString<T> {
-        let pol1 = self.point_on_line(line, start);
-        let pol2 = self.point_on_line(line, stop);
+        self.line_slice_coords(start, stop, &line.0)
+    }
+
+    /// Returns a part of the given coordinates between the start and the stop
+    /// points (or their closest points on the line). Only the returned line
+    /// is allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start point
+    /// * `stop` - Stop point
+    /// * `line` - Coordinates of the line
+    pub fn line_slice_coords<P, C>(
+        &self,
+        start: &P,
+        stop: &P,
+        line: &[C],
+    ) -> LineString<T>
+    where
+        P: LngLat<T>,
+        C: LngLat<T>,
+    {
+        let pol1 = self.point_on_line_coords(line, start);
+        let pol2 = self.point_on_line_coords(line, stop);
 
         if pol1.is_none() || pol2.is_none() {
             return line_string![];
@@ -416,17 +1074,17 @@ where
         let l = pol1.index() + 1;
         let r = pol2.index();
 
-        if line[l] != slice[0].into() && l <= r {
-            slice.push(line[l].into());
+        if !same_point(&to_point(&line[l]), &slice[0]) && l <= r {
+            slice.push(to_point(&line[l]));
         }
 
         let mut i = l + 1;
         while i <= r {
-            slice.push(line[i].into());
+            slice.push(to_point(&line[i]));
             i += 1;
         }
 
-        if line[r] != pol2.point().into() {
+        if !same_point(&to_point(&line[r]), &pol2.point()) {
             slice.push(pol2.point());
         }
 
@@ -445,17 +1103,37 @@ where
         stop: T,
         line: &LineString<T>,
     ) -> LineString<T> {
+        self.line_slice_along_coords(start, stop, &line.0)
+    }
+
+    /// Returns a part of the given coordinates between the start and the stop
+    /// points indicated by distance along the line. Only the returned line is
+    /// allocated.
+    ///
+    /// * `start` - Start distance
+    /// * `stop` - Stop distance
+    /// * `coords` - Slice or iterator of coordinates
+    pub fn line_slice_along_coords<I>(
+        &self,
+        start: T,
+        stop: T,
+        coords: I,
+    ) -> LineString<T>
+    where
+        I: IntoIterator,
+        I::Item: LngLat<T>,
+    {
         let mut sum = T::zero();
         let mut slice = vec![];
 
-        let line_len = line.0.len();
-        if line_len == 0 {
-            return slice.into();
-        }
+        let mut coords = coords.into_iter();
+        let mut p0 = match coords.next() {
+            Some(c) => to_point(&c),
+            None => return slice.into(),
+        };
 
-        for i in 0..line_len - 1 {
-            let p0 = line[i].into();
-            let p1 = line[i + 1].into();
+        for c in coords {
+            let p1 = to_point(&c);
             let d = self.distance(&p0, &p1);
 
             sum = sum + d;
@@ -472,6 +1150,8 @@ where
             if sum > start {
                 slice.push(p1);
             }
+
+            p0 = p1;
         }
 
         slice.into()
@@ -484,18 +1164,18 @@ where
     ///
     /// * `p` - Point
     /// * `buffer` - Buffer distance
-    pub fn buffer_point(&self, p: &Point<T>, buffer: T) -> Rect<T> {
+    pub fn buffer_point<P: LngLat<T>>(&self, p: &P, buffer: T) -> Rect<T> {
         let v = buffer / self.ky;
         let h = buffer / self.kx;
 
         Rect::new(
             Coordinate {
-                x: p.x() - h,
-                y: p.y() - v,
+                x: p.lng() - h,
+                y: p.lat() - v,
             },
             Coordinate {
-                x: p.x() + h,
-                y: p.y() + v,
+                x: p.lng() + h,
+                y: p.lat() + v,
             },
         )
     }
@@ -529,11 +1209,11 @@ where
     ///
     /// * `p` - Point
     /// * `bbox` - Bounding box
-    pub fn inside_bbox(&self, p: &Point<T>, bbox: &Rect<T>) -> bool {
-        p.y() >= bbox.min().y
-            && p.y() <= bbox.max().y
-            && long_diff(p.x(), bbox.min().x) >= T::zero()
-            && long_diff(p.x(), bbox.max().x) <= T::zero()
+    pub fn inside_bbox<P: LngLat<T>>(&self, p: &P, bbox: &Rect<T>) -> bool {
+        p.lat() >= bbox.min().y
+            && p.lat() <= bbox.max().y
+            && long_diff(p.lng(), bbox.min().x) >= T::zero()
+            && long_diff(p.lng(), bbox.max().x) <= T::zero()
     }
 }
 
@@ -544,37 +1224,100 @@ pub fn interpolate<T: Float + fmt::Debug>(
 ) -> Point<T> {
     let dx = long_diff(b.x(), a.x());
     let dy = b.y() - a.y();
-    Point::new(a.x() + dx * t, a.y() + dy * t)
+    let x = wrap_longitude(a.x() + dx * t, &[a.x(), b.x()]);
+    Point::new(x, a.y() + dy * t)
+}
+
+/// Returns the unitless multipliers for converting longitude and latitude
+/// degrees into distance at the given latitude, on an ellipsoid with the given
+/// squared eccentricity
+fn curvature_multipliers<T: Float>(latitude: T, e2: T) -> (T, T) {
+    let one = T::one();
+
+    // Curvature formulas from https://en.wikipedia.org/wiki/Earth_radius#Meridional
+    let coslat = latitude.to_radians().cos();
+    let w2 = one / (one - e2 * (one - coslat * coslat));
+    let w = w2.sqrt();
+
+    let dkx = w * coslat; // based on normal radius of curvature
+    let dky = w * w2 * (one - e2); // based on meridonal radius of curvature
+
+    (dkx, dky)
 }
 
 fn calculate_multipliers<T: Float>(
-    distance_unit: DistanceUnit,
+    conversion_factor: T,
+    re: T,
     dkx: T,
     dky: T,
 ) -> (T, T) {
-    let re = T::from(RE).unwrap();
-    let mul = distance_unit
-        .conversion_factor_kilometers::<T>()
-        .to_radians()
-        * re;
+    let mul = conversion_factor.to_radians() * re;
     let kx = mul * dkx;
     let ky = mul * dky;
     (kx, ky)
 }
 
+/// Returns the latitude of the center of a tile row, or None if a number
+/// cannot be represented in the float type. The zoom level must be below 32.
+fn tile_latitude<T: Float>(y: u32, z: u32) -> Option<T> {
+    let n = T::from(f64::consts::PI)?
+        * (T::one()
+            - T::from(2.0)? * (T::from(y)? + T::from(0.5)?)
+                / T::from(1u32 << z)?);
+    Some(n.sinh().atan().to_degrees())
+}
+
+fn convert<T: Float>(value: f64) -> Result<T, Error> {
+    T::from(value).ok_or(Error::NumericConversion)
+}
+
 fn long_diff<T: Float>(a: T, b: T) -> T {
     let threesixty = T::from(360).unwrap();
     let diff = a - b;
     diff - ((diff / threesixty).round() * threesixty)
 }
 
-fn sum_area<T: Float + fmt::Debug>(line: &[Point<T>]) -> T {
-    let line_len = line.len();
+/// Brings a longitude that has crossed the antimeridian back into ±180
+/// degrees, if all the longitudes it was computed from were in that range.
+/// Otherwise the input is unwrapped, as GeoJSON allows across the
+/// antimeridian, and its frame is kept.
+fn wrap_longitude<T: Float>(x: T, inputs: &[T]) -> T {
+    let half_turn = T::from(180).unwrap();
+    if x.abs() > half_turn && inputs.iter().all(|i| i.abs() <= half_turn) {
+        long_diff(x, T::zero())
+    } else {
+        x
+    }
+}
+
+/// Returns true if both points are at the same location, treating longitudes
+/// 360 degrees apart as equal
+fn same_point<T: Float + fmt::Debug>(a: &Point<T>, b: &Point<T>) -> bool {
+    a.y() == b.y() && long_diff(a.x(), b.x()) == T::zero()
+}
+
+fn sum_area<T, I>(ring: I) -> T
+where
+    T: Float + fmt::Debug,
+    I: IntoIterator,
+    I::Item: LngLat<T>,
+{
+    let mut ring = ring.into_iter().map(|c| coord!(x: c.lng(), y: c.lat()));
+    let first = match ring.next() {
+        Some(c) => c,
+        None => return T::zero(),
+    };
+
+    let term =
+        |j: Coordinate<T>, k: Coordinate<T>| long_diff(j.x, k.x) * (j.y + k.y);
+
+    let mut k = first;
     let mut sum = T::zero();
-    let mut k = line_len - 1;
-    for j in 0..line_len {
-        sum = sum + (line[j].x() - line[k].x()) * (line[j].y() + line[k].y());
+    for j in ring {
+        sum = sum + term(j, k);
         k = j;
     }
-    sum
+
+    // closes the ring, which adds nothing if it is already closed
+    sum + term(first, k)
 }
@@ -0,0 +1,100 @@
+use geo_types::{CoordNum, Coordinate, Point};
+
+/// A geographical position given as a longitude and a latitude in degrees
+///
+/// [`CheapRuler`](crate::CheapRuler) methods accept any type implementing this
+/// trait, so that positions don't have to be converted into
+/// [`geo_types::Point`] before they are measured. It is implemented for
+/// geo_types points and coordinates, `(lng, lat)` tuples and `[lng, lat]`
+/// arrays, and can be implemented for other types.
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::{CheapRuler, DistanceUnit, LngLat};
+///
+/// struct Position {
+///     lon: f64,
+///     lat: f64,
+/// }
+///
+/// impl LngLat<f64> for Position {
+///     fn lng(&self) -> f64 {
+///         self.lon
+///     }
+///
+///     fn lat(&self) -> f64 {
+///         self.lat
+///     }
+/// }
+///
+/// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+/// let a = Position { lon: 14.8901816, lat: 44.7209699 };
+/// let b = [14.8905188, 44.7209699];
+/// assert!(cr.distance(&a, &b) < 38.0);
+/// ```
+pub trait LngLat<T> {
+    /// Returns the longitude in degrees
+    fn lng(&self) -> T;
+
+    /// Returns the latitude in degrees
+    fn lat(&self) -> T;
+}
+
+impl<T: CoordNum> LngLat<T> for Point<T> {
+    fn lng(&self) -> T {
+        self.x()
+    }
+
+    fn lat(&self) -> T {
+        self.y()
+    }
+}
+
+impl<T: CoordNum> LngLat<T> for Coordinate<T> {
+    fn lng(&self) -> T {
+        self.x
+    }
+
+    fn lat(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: Copy> LngLat<T> for (T, T) {
+    fn lng(&self) -> T {
+        self.0
+    }
+
+    fn lat(&self) -> T {
+        self.1
+    }
+}
+
+impl<T: Copy> LngLat<T> for [T; 2] {
+    fn lng(&self) -> T {
+        self[0]
+    }
+
+    fn lat(&self) -> T {
+        self[1]
+    }
+}
+
+impl<T, P> LngLat<T> for &P
+where
+    P: LngLat<T> + ?Sized,
+{
+    fn lng(&self) -> T {
+        (**self).lng()
+    }
+
+    fn lat(&self) -> T {
+        (**self).lat()
+    }
+}
+
+/// Converts a position into a geo_types point
+pub(crate) fn to_point<T: CoordNum, P: LngLat<T>>(p: &P) -> Point<T> {
+    Point::new(p.lng(), p.lat())
+}
@@ -0,0 +1,121 @@
+use crate::{long_diff, wrap_longitude, CheapMapCoords, CheapRuler, LngLat};
+use geo_types::{Coordinate, Point};
+use num_traits::Float;
+use std::fmt;
+
+/// A local tangent plane around an origin point, with coordinates given as
+/// easting and northing distances from the origin in the ruler's units
+///
+/// Projecting is the inverse of [`CheapRuler::offset`], which makes the
+/// frame useful for running planar algorithms on nearby geographic data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocalFrame<T>
+where
+    T: Float + fmt::Debug,
+{
+    ruler: CheapRuler<T>,
+    origin: Point<T>,
+}
+
+impl<T> LocalFrame<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a local frame around the origin point, measured with the ruler
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure distances with
+    /// * `origin` - Origin point of the frame
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, LocalFrame};
+    /// use geo_types::Point;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let frame = LocalFrame::new(&cr, &(14.8901816, 44.7209699));
+    ///
+    /// let point: Point<f64> = Point::new(14.8905188, 44.7219699);
+    /// let projected = frame.project(&point);
+    /// assert!(projected.x() > 0.0 && projected.y() > 0.0);
+    ///
+    /// let unprojected = frame.unproject(&projected);
+    /// assert!((unprojected.x() - point.x()).abs() < 1e-12);
+    /// assert!((unprojected.y() - point.y()).abs() < 1e-12);
+    /// ```
+    pub fn new<P: LngLat<T>>(ruler: &CheapRuler<T>, origin: &P) -> Self {
+        Self {
+            ruler: ruler.clone(),
+            origin: Point::new(origin.lng(), origin.lat()),
+        }
+    }
+
+    /// Ruler the frame measures distances with
+    pub fn ruler(&self) -> &CheapRuler<T> {
+        &self.ruler
+    }
+
+    /// Origin point of the frame
+    pub fn origin(&self) -> Point<T> {
+        self.origin
+    }
+
+    /// Returns the easting and northing distances from the origin to the
+    /// given point
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Point to project
+    pub fn project<P: LngLat<T>>(&self, point: &P) -> Point<T> {
+        Point(self.project_coord(coord!(x: point.lng(), y: point.lat())))
+    }
+
+    /// Returns the point at the given easting and northing distances from
+    /// the origin. This is the inverse of [`LocalFrame::project`].
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Easting and northing distances from the origin
+    pub fn unproject(&self, point: &Point<T>) -> Point<T> {
+        Point(self.unproject_coord(point.0))
+    }
+
+    /// Returns a copy of the geometry with every coordinate projected to the
+    /// frame. See [`LocalFrame::project`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to project
+    pub fn project_geometry<G: CheapMapCoords<T>>(&self, geometry: &G) -> G {
+        geometry.cheap_map_coords(&|c| self.project_coord(c))
+    }
+
+    /// Returns a copy of the geometry with every coordinate unprojected from
+    /// the frame. See [`LocalFrame::unproject`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to unproject
+    pub fn unproject_geometry<G: CheapMapCoords<T>>(&self, geometry: &G) -> G {
+        geometry.cheap_map_coords(&|c| self.unproject_coord(c))
+    }
+
+    fn project_coord(&self, c: Coordinate<T>) -> Coordinate<T> {
+        coord! {
+            x: long_diff(c.x, self.origin.x()) * self.ruler.kx,
+            y: (c.y - self.origin.y()) * self.ruler.ky,
+        }
+    }
+
+    fn unproject_coord(&self, c: Coordinate<T>) -> Coordinate<T> {
+        coord! {
+            x: wrap_longitude(
+                self.origin.x() + c.x / self.ruler.kx,
+                &[self.origin.x()],
+            ),
+            y: self.origin.y() + c.y / self.ruler.ky,
+        }
+    }
+}
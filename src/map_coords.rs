@@ -0,0 +1,166 @@
+use geo_types::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Triangle,
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Geometries whose coordinates can be transformed one by one, keeping the
+/// geometry's structure
+///
+/// Used by [`LocalFrame`](crate::LocalFrame) to project whole geometries.
+///
+/// `Rect` isn't implemented, as a rotated or wrapped rectangle is no longer
+/// axis-aligned. A `Geometry::Rect` is mapped to a `Geometry::Polygon`.
+pub trait CheapMapCoords<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns a copy of the geometry with the function applied to each of
+    /// its coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function mapping a coordinate to its new value
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>;
+}
+
+impl<T> CheapMapCoords<T> for Coordinate<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        f(*self)
+    }
+}
+
+impl<T> CheapMapCoords<T> for Point<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        Point(f(self.0))
+    }
+}
+
+impl<T> CheapMapCoords<T> for Line<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        Line::new(f(self.start), f(self.end))
+    }
+}
+
+impl<T> CheapMapCoords<T> for LineString<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        self.0.iter().map(|&c| f(c)).collect()
+    }
+}
+
+impl<T> CheapMapCoords<T> for Polygon<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        Polygon::new(
+            self.exterior().cheap_map_coords(f),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.cheap_map_coords(f))
+                .collect(),
+        )
+    }
+}
+
+impl<T> CheapMapCoords<T> for Triangle<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        Triangle(f(self.0), f(self.1), f(self.2))
+    }
+}
+
+macro_rules! map_members {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T> CheapMapCoords<T> for $geometry<T>
+            where
+                T: Float + fmt::Debug,
+            {
+                fn cheap_map_coords<F>(&self, f: &F) -> Self
+                where
+                    F: Fn(Coordinate<T>) -> Coordinate<T>,
+                {
+                    $geometry(self.0.iter().map(|g| g.cheap_map_coords(f)).collect())
+                }
+            }
+        )*
+    };
+}
+
+map_members!(
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T> CheapMapCoords<T> for Geometry<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_map_coords<F>(&self, f: &F) -> Self
+    where
+        F: Fn(Coordinate<T>) -> Coordinate<T>,
+    {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.cheap_map_coords(f)),
+            Geometry::Line(g) => Geometry::Line(g.cheap_map_coords(f)),
+            Geometry::LineString(g) => {
+                Geometry::LineString(g.cheap_map_coords(f))
+            }
+            Geometry::Polygon(g) => Geometry::Polygon(g.cheap_map_coords(f)),
+            Geometry::MultiPoint(g) => {
+                Geometry::MultiPoint(g.cheap_map_coords(f))
+            }
+            Geometry::MultiLineString(g) => {
+                Geometry::MultiLineString(g.cheap_map_coords(f))
+            }
+            Geometry::MultiPolygon(g) => {
+                Geometry::MultiPolygon(g.cheap_map_coords(f))
+            }
+            Geometry::GeometryCollection(g) => {
+                Geometry::GeometryCollection(g.cheap_map_coords(f))
+            }
+            Geometry::Rect(g) => {
+                Geometry::Polygon(g.to_polygon().cheap_map_coords(f))
+            }
+            Geometry::Triangle(g) => Geometry::Triangle(g.cheap_map_coords(f)),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::CheapRuler;
+use geo_types::{
+    Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Geometries that can be measured with a [`CheapRuler`]
+///
+/// Length is the total length of a geometry's lines, and perimeter is the
+/// total length of its polygon rings, including holes. Geometries without
+/// lines or polygons, such as points, measure zero.
+pub trait Measure<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the total length of the geometry's lines
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure with
+    fn length(&self, ruler: &CheapRuler<T>) -> T;
+
+    /// Returns the total length of the geometry's polygon rings
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure with
+    fn perimeter(&self, ruler: &CheapRuler<T>) -> T;
+
+    /// Returns the total area of the geometry's polygons
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure with
+    fn area(&self, ruler: &CheapRuler<T>) -> T;
+}
+
+macro_rules! zero_measure {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T> Measure<T> for $geometry<T>
+            where
+                T: Float + fmt::Debug,
+            {
+                fn length(&self, _ruler: &CheapRuler<T>) -> T {
+                    T::zero()
+                }
+
+                fn perimeter(&self, _ruler: &CheapRuler<T>) -> T {
+                    T::zero()
+                }
+
+                fn area(&self, _ruler: &CheapRuler<T>) -> T {
+                    T::zero()
+                }
+            }
+        )*
+    };
+}
+
+zero_measure!(Point, MultiPoint);
+
+macro_rules! sum_measure {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T> Measure<T> for $geometry<T>
+            where
+                T: Float + fmt::Debug,
+            {
+                fn length(&self, ruler: &CheapRuler<T>) -> T {
+                    sum(self.0.iter().map(|g| g.length(ruler)))
+                }
+
+                fn perimeter(&self, ruler: &CheapRuler<T>) -> T {
+                    sum(self.0.iter().map(|g| g.perimeter(ruler)))
+                }
+
+                fn area(&self, ruler: &CheapRuler<T>) -> T {
+                    sum(self.0.iter().map(|g| g.area(ruler)))
+                }
+            }
+        )*
+    };
+}
+
+sum_measure!(MultiLineString, MultiPolygon, GeometryCollection);
+
+impl<T> Measure<T> for Line<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, ruler: &CheapRuler<T>) -> T {
+        ruler.distance(&self.start_point(), &self.end_point())
+    }
+
+    fn perimeter(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+
+    fn area(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+}
+
+impl<T> Measure<T> for LineString<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, ruler: &CheapRuler<T>) -> T {
+        ruler.line_distance(self)
+    }
+
+    fn perimeter(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+
+    fn area(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+}
+
+impl<T> Measure<T> for Polygon<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+
+    fn perimeter(&self, ruler: &CheapRuler<T>) -> T {
+        ruler.line_distance(self.exterior())
+            + sum(self.interiors().iter().map(|i| ruler.line_distance(i)))
+    }
+
+    fn area(&self, ruler: &CheapRuler<T>) -> T {
+        ruler.area_coords(
+            &self.exterior().0,
+            self.interiors().iter().map(|i| &i.0),
+        )
+    }
+}
+
+impl<T> Measure<T> for Rect<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+
+    fn perimeter(&self, ruler: &CheapRuler<T>) -> T {
+        self.to_polygon().perimeter(ruler)
+    }
+
+    fn area(&self, ruler: &CheapRuler<T>) -> T {
+        self.to_polygon().area(ruler)
+    }
+}
+
+impl<T> Measure<T> for Triangle<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, _ruler: &CheapRuler<T>) -> T {
+        T::zero()
+    }
+
+    fn perimeter(&self, ruler: &CheapRuler<T>) -> T {
+        self.to_polygon().perimeter(ruler)
+    }
+
+    fn area(&self, ruler: &CheapRuler<T>) -> T {
+        self.to_polygon().area(ruler)
+    }
+}
+
+macro_rules! geometry_dispatch {
+    ($self:expr, $method:ident, $ruler:expr) => {
+        match $self {
+            Geometry::Point(g) => g.$method($ruler),
+            Geometry::Line(g) => g.$method($ruler),
+            Geometry::LineString(g) => g.$method($ruler),
+            Geometry::Polygon(g) => g.$method($ruler),
+            Geometry::MultiPoint(g) => g.$method($ruler),
+            Geometry::MultiLineString(g) => g.$method($ruler),
+            Geometry::MultiPolygon(g) => g.$method($ruler),
+            Geometry::GeometryCollection(g) => g.$method($ruler),
+            Geometry::Rect(g) => g.$method($ruler),
+            Geometry::Triangle(g) => g.$method($ruler),
+        }
+    };
+}
+
+impl<T> Measure<T> for Geometry<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn length(&self, ruler: &CheapRuler<T>) -> T {
+        geometry_dispatch!(self, length, ruler)
+    }
+
+    fn perimeter(&self, ruler: &CheapRuler<T>) -> T {
+        geometry_dispatch!(self, perimeter, ruler)
+    }
+
+    fn area(&self, ruler: &CheapRuler<T>) -> T {
+        geometry_dispatch!(self, area, ruler)
+    }
+}
+
+// avoided using Iterator's sum() so that we don't have to require T to
+// implement std::iter::Sum.
+fn sum<T: Float>(values: impl Iterator<Item = T>) -> T {
+    values.fold(T::zero(), |acc, x| acc + x)
+}
@@ -0,0 +1,244 @@
+use crate::{interpolate, CheapRuler, LngLat, PointOnLine, Side};
+use geo_types::{LineString, Point};
+use num_traits::Float;
+use std::fmt;
+
+/// A line with the cumulative distance to each of its vertices precomputed,
+/// for answering repeated queries along the line in O(log n)
+///
+/// Results are identical to the corresponding [`CheapRuler`] methods, which
+/// measure the line from its start on every call.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeasuredLine<T>
+where
+    T: Float + fmt::Debug,
+{
+    ruler: CheapRuler<T>,
+    line: LineString<T>,
+    measures: Vec<T>,
+}
+
+impl<T> MeasuredLine<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Measures the given line with the ruler
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure the line with
+    /// * `line` - Line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, MeasuredLine};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let measured = MeasuredLine::new(&cr, line_string.clone());
+    /// assert_eq!(measured.length(), cr.line_distance(&line_string));
+    /// assert_eq!(measured.along(1000.0), cr.along(&line_string, 1000.0));
+    /// ```
+    pub fn new(ruler: &CheapRuler<T>, line: LineString<T>) -> Self {
+        let mut sum = T::zero();
+        let measures = line
+            .0
+            .iter()
+            .scan(None, |prev, &c| {
+                if let Some(p) = prev.replace(c) {
+                    sum = sum + ruler.distance(&p, &c);
+                }
+                Some(sum)
+            })
+            .collect();
+
+        Self {
+            ruler: ruler.clone(),
+            line,
+            measures,
+        }
+    }
+
+    /// Returns the ruler the line was measured with
+    pub fn ruler(&self) -> &CheapRuler<T> {
+        &self.ruler
+    }
+
+    /// Returns the line
+    pub fn line(&self) -> &LineString<T> {
+        &self.line
+    }
+
+    /// Returns the distance along the line to each of its vertices
+    pub fn measures(&self) -> &[T] {
+        &self.measures
+    }
+
+    /// Returns the total line distance
+    pub fn length(&self) -> T {
+        self.measures.last().copied().unwrap_or_else(T::zero)
+    }
+
+    /// Returns the point at a specified distance along the line. See
+    /// [`CheapRuler::along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Distance along the line
+    pub fn along(&self, dist: T) -> Option<Point<T>> {
+        let last_index = self.measures.len().checked_sub(1)?;
+
+        if dist <= T::zero() {
+            return Some(self.point(0));
+        }
+
+        // a NaN distance is never reached, like in CheapRuler::along
+        match self.segment_after(|m| m <= dist || dist.is_nan()) {
+            Some(i) => Some(self.interpolate(i, dist)),
+            None => Some(self.point(last_index)),
+        }
+    }
+
+    /// Returns the point at a specified distance along the line, along with
+    /// the start index of its segment and its position on the segment. See
+    /// [`CheapRuler::locate_along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dist` - Distance along the line
+    pub fn locate_along(&self, dist: T) -> Option<PointOnLine<T>> {
+        let zero = T::zero();
+        let last_index = self.measures.len().checked_sub(1)?;
+
+        let length = self.measures[last_index];
+
+        if dist <= zero {
+            // the first segment with a length gives the direction
+            let index = self.segment_after(|m| m <= zero).unwrap_or(0);
+            let bearing = self.bearing(index).unwrap_or(zero);
+            return Some(PointOnLine::new(
+                self.point(0),
+                index,
+                zero,
+                zero,
+                zero,
+                bearing,
+                Side::On,
+            ));
+        }
+
+        Some(match self.segment_after(|m| m <= dist || dist.is_nan()) {
+            Some(i) => {
+                let (point, t) = self.interpolate_t(i, dist);
+                let bearing = self.bearing(i).unwrap_or(zero);
+                PointOnLine::new(point, i, t, zero, dist, bearing, Side::On)
+            }
+            None if length > zero => {
+                // the last segment with a length gives the direction
+                let index = self.segment_after(|m| m < length).unwrap_or(0);
+                PointOnLine::new(
+                    self.point(last_index),
+                    index,
+                    T::one(),
+                    zero,
+                    length,
+                    self.bearing(index).unwrap_or(zero),
+                    Side::On,
+                )
+            }
+            None => PointOnLine::new(
+                self.point(last_index),
+                0,
+                zero,
+                zero,
+                zero,
+                zero,
+                Side::On,
+            ),
+        })
+    }
+
+    /// Returns the distance along the line to the closest point on the line
+    /// from the given point. See [`CheapRuler::distance_along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Point to measure the distance along the line for
+    pub fn distance_along<P: LngLat<T>>(&self, point: &P) -> Option<T> {
+        self.ruler.distance_along(&self.line, point)
+    }
+
+    /// Returns a part of the line between the start and the stop points
+    /// indicated by distance along the line. See
+    /// [`CheapRuler::line_slice_along`].
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start distance
+    /// * `stop` - Stop distance
+    pub fn line_slice_along(&self, start: T, stop: T) -> LineString<T> {
+        let first = self.segment_after(|m| m <= start || start.is_nan());
+        let last = self.segment_after(|m| m < stop || stop.is_nan());
+
+        let mut slice = vec![];
+        match (first, last) {
+            (Some(i), Some(j)) if i <= j => {
+                slice.push(self.interpolate(i, start));
+                slice.extend((i + 1..=j).map(|k| self.point(k)));
+                slice.push(self.interpolate(j, stop));
+            }
+            (_, Some(j)) => slice.push(self.interpolate(j, stop)),
+            (Some(i), None) => {
+                slice.push(self.interpolate(i, start));
+                slice.extend(
+                    (i + 1..self.measures.len()).map(|k| self.point(k)),
+                );
+            }
+            (None, None) => {}
+        }
+
+        slice.into()
+    }
+
+    /// Returns the index of the first segment whose end is not within the
+    /// measures matching the predicate, if there is one
+    fn segment_after<P>(&self, pred: P) -> Option<usize>
+    where
+        P: Fn(T) -> bool,
+    {
+        let ends = self.measures.get(1..)?;
+        let i = ends.partition_point(|&m| pred(m));
+        Some(i).filter(|&i| i < ends.len())
+    }
+
+    /// Returns the bearing of the given segment, if the line has it
+    fn bearing(&self, segment: usize) -> Option<T> {
+        let end = self.line.0.get(segment + 1)?;
+        Some(self.ruler.bearing(&self.line[segment], end))
+    }
+
+    /// Interpolates the point at the given distance along the line on the
+    /// given segment, the same way as [`CheapRuler::along`]
+    fn interpolate(&self, segment: usize, dist: T) -> Point<T> {
+        self.interpolate_t(segment, dist).0
+    }
+
+    fn interpolate_t(&self, segment: usize, dist: T) -> (Point<T>, T) {
+        let p0 = self.point(segment);
+        let p1 = self.point(segment + 1);
+        let d = self.ruler.distance(&p0, &p1);
+        let sum = self.measures[segment + 1];
+        let t = (dist - (sum - d)) / d;
+        (interpolate(&p0, &p1, t), t)
+    }
+
+    fn point(&self, index: usize) -> Point<T> {
+        self.line[index].into()
+    }
+}
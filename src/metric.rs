@@ -0,0 +1,113 @@
+use crate::{CheapRuler, LngLat};
+use geo_types::Point;
+use num_traits::Float;
+use std::fmt;
+
+/// A model for measuring distances and bearings between geographical points
+///
+/// Algorithms written against this trait can choose between the speed of
+/// [`CheapRuler`] and the global accuracy of models such as
+/// [`Haversine`](crate::Haversine).
+///
+/// # Examples
+///
+/// ```
+/// use cheap_ruler::{CheapRuler, DistanceUnit, Haversine, Metric};
+/// use geo_types::{point, Point};
+///
+/// fn round_trip<M: Metric<f64>>(metric: &M, a: &Point<f64>) -> Point<f64> {
+///     let b = metric.destination(a, 1000.0, 30.0);
+///     let bearing = metric.bearing(&b, a);
+///     metric.destination(&b, metric.distance(&b, a), bearing)
+/// }
+///
+/// let a = point!(x: 14.8901816, y: 44.7209699);
+/// let cheap = round_trip(&CheapRuler::new(44.72, DistanceUnit::Meters), &a);
+/// let haversine = round_trip(&Haversine::new(DistanceUnit::Meters), &a);
+/// assert!((cheap.x() - a.x()).abs() < 1e-9);
+/// assert!((haversine.x() - a.x()).abs() < 1e-9);
+/// ```
+pub trait Metric<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Calculates the distance between two geographical points
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    fn distance<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>;
+
+    /// Returns the bearing between two points in angles
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First point
+    /// * `b` - Second point
+    fn bearing<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>;
+
+    /// Returns a new point given distance and bearing from the starting point
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - origin point
+    /// * `dist` - distance
+    /// * `bearing` - bearing
+    fn destination<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        dist: T,
+        bearing: T,
+    ) -> Point<T>;
+
+    /// Returns a new point given easting and northing offsets from the
+    /// starting point
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - point
+    /// * `dx` - easting
+    /// * `dy` - northing
+    fn offset<P: LngLat<T>>(&self, origin: &P, dx: T, dy: T) -> Point<T>;
+}
+
+impl<T> Metric<T> for CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn distance<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        CheapRuler::distance(self, a, b)
+    }
+
+    fn bearing<A, B>(&self, a: &A, b: &B) -> T
+    where
+        A: LngLat<T>,
+        B: LngLat<T>,
+    {
+        CheapRuler::bearing(self, a, b)
+    }
+
+    fn destination<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        dist: T,
+        bearing: T,
+    ) -> Point<T> {
+        CheapRuler::destination(self, origin, dist, bearing)
+    }
+
+    fn offset<P: LngLat<T>>(&self, origin: &P, dx: T, dy: T) -> Point<T> {
+        CheapRuler::offset(self, origin, dx, dy)
+    }
+}
@@ -0,0 +1,97 @@
+//! Parallel variants of bulk operations, enabled with the `rayon` feature.
+//!
+//! Every method gives the same results as calling the corresponding
+//! [`CheapRuler`] method sequentially over its input.
+
+use crate::{CheapRuler, LngLat, PointOnLine};
+use geo_types::{LineString, Polygon};
+use num_traits::Float;
+use rayon::prelude::*;
+use std::fmt;
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug + Send + Sync,
+{
+    /// Calculates the approximate distances from an origin point to each of
+    /// the given points in parallel, writing them into `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin point
+    /// * `points` - Points to measure the distance to
+    /// * `out` - Output buffer, one distance per point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is not the same length as `points`.
+    pub fn par_distances<O, P>(&self, origin: &O, points: &[P], out: &mut [T])
+    where
+        O: LngLat<T> + Sync,
+        P: LngLat<T> + Sync,
+    {
+        assert_eq!(points.len(), out.len());
+
+        points
+            .par_iter()
+            .zip(out.par_iter_mut())
+            .for_each(|(p, d)| *d = self.distance(origin, p));
+    }
+
+    /// Returns the total distance of each of the given lines, measured in
+    /// parallel
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Lines to measure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let lines: Vec<LineString<f64>> = vec![
+    ///     vec![(-67.031, 50.458), (-67.031, 50.534)].into(),
+    ///     vec![(-66.929, 50.534), (-66.929, 50.458)].into(),
+    /// ];
+    /// let lengths = cr.par_line_distances(&lines);
+    /// assert_eq!(lengths[0], cr.line_distance(&lines[0]));
+    /// ```
+    pub fn par_line_distances(&self, lines: &[LineString<T>]) -> Vec<T> {
+        lines
+            .par_iter()
+            .map(|line| self.line_distance(line))
+            .collect()
+    }
+
+    /// Returns the area of each of the given polygons, measured in parallel
+    ///
+    /// # Arguments
+    ///
+    /// * `polygons` - Polygons to measure
+    pub fn par_areas(&self, polygons: &[Polygon<T>]) -> Vec<T> {
+        polygons
+            .par_iter()
+            .map(|polygon| self.area(polygon))
+            .collect()
+    }
+
+    /// Returns the closest point on the line for each of the given points,
+    /// searched in parallel. See [`CheapRuler::point_on_line`].
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to compare with the points
+    /// * `points` - Points to calculate the closest points on the line for
+    pub fn par_points_on_line<P: LngLat<T> + Sync>(
+        &self,
+        line: &LineString<T>,
+        points: &[P],
+    ) -> Vec<Option<PointOnLine<T>>> {
+        points
+            .par_iter()
+            .map(|point| self.point_on_line(line, point))
+            .collect()
+    }
+}
@@ -3,6 +3,20 @@ use num_traits::cast::NumCast;
 use num_traits::Num;
 use std::fmt;
 
+/// Side of a line that a point lies on, looking along the line's direction
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Left,
+    Right,
+    On,
+}
+
+/// The closest point on a line to a query point, as returned by
+/// [`CheapRuler::point_on_line`](crate::CheapRuler::point_on_line)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PointOnLine<T>
 where
     T: Num + NumCast + Copy + PartialEq + PartialOrd + fmt::Debug,
@@ -10,25 +24,81 @@ where
     point: Point<T>,
     index: usize,
     t: T,
+    distance: T,
+    along: T,
+    bearing: T,
+    side: Side,
 }
 
 impl<T> PointOnLine<T>
 where
     T: Num + NumCast + Copy + PartialEq + PartialOrd + fmt::Debug,
 {
-    pub fn new(point: Point<T>, index: usize, t: T) -> Self {
-        Self { point, index, t }
+    /// Creates a closest point on a line
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Closest point on the line
+    /// * `index` - Start index of the segment with the closest point
+    /// * `t` - Where the closest point is on its segment, from 0 to 1
+    /// * `distance` - Distance from the query point to the closest point
+    /// * `along` - Distance along the line to the closest point
+    /// * `bearing` - Bearing of the segment with the closest point
+    /// * `side` - Side of the line the query point lies on
+    pub fn new(
+        point: Point<T>,
+        index: usize,
+        t: T,
+        distance: T,
+        along: T,
+        bearing: T,
+        side: Side,
+    ) -> Self {
+        Self {
+            point,
+            index,
+            t,
+            distance,
+            along,
+            bearing,
+            side,
+        }
     }
 
+    /// Returns the closest point on the line
     pub fn point(&self) -> Point<T> {
         self.point
     }
 
+    /// Returns the start index of the segment with the closest point
     pub fn index(&self) -> usize {
         self.index
     }
 
+    /// Returns where the closest point is on its segment, from 0 at its start
+    /// to 1 at its end
     pub fn t(&self) -> T {
         self.t
     }
+
+    /// Returns the distance from the query point to the closest point
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+
+    /// Returns the distance along the line to the closest point
+    pub fn along(&self) -> T {
+        self.along
+    }
+
+    /// Returns the bearing of the segment with the closest point
+    pub fn bearing(&self) -> T {
+        self.bearing
+    }
+
+    /// Returns the side of the segment with the closest point that the query
+    /// point lies on
+    pub fn side(&self) -> Side {
+        self.side
+    }
 }
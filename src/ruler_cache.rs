@@ -0,0 +1,271 @@
+use crate::{curvature_multipliers, CheapRuler, DistanceUnit, Ellipsoid};
+use num_traits::Float;
+use std::fmt;
+
+/// A table of rulers precomputed for latitude bands of a fixed width.
+///
+/// Creating a ruler with [`CheapRuler::new`] requires trigonometric functions
+/// and a square root. The cache performs that work once per band when it is
+/// constructed, so that rulers for any latitude and distance unit can be
+/// looked up cheaply. The cache is immutable after construction and can be
+/// shared across threads.
+#[derive(Debug, Clone)]
+pub struct RulerCache<T>
+where
+    T: Float + fmt::Debug,
+{
+    step: T,
+    ellipsoid: Ellipsoid,
+    multipliers: Vec<(T, T)>,
+}
+
+impl<T> RulerCache<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a cache of rulers for latitude bands of `step` degrees, centered
+    /// on -90 plus multiples of `step`. The centers are multiples of `step` if
+    /// 90 is a multiple of `step`.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Width of a latitude band in degrees
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not a positive, finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, RulerCache};
+    /// let cache = RulerCache::new(0.1);
+    /// let latitude = 44.7192003;
+    /// let ruler = cache.ruler(latitude, DistanceUnit::Meters);
+    /// assert_eq!(
+    ///     ruler,
+    ///     CheapRuler::new(cache.band_latitude(latitude), DistanceUnit::Meters)
+    /// );
+    /// ```
+    pub fn new(step: T) -> Self {
+        Self::with_ellipsoid(step, Ellipsoid::WGS84)
+    }
+
+    /// Creates a cache of rulers for latitude bands of `step` degrees, centered
+    /// on -90 plus multiples of `step`, with the curvature of the given
+    /// ellipsoid
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Width of a latitude band in degrees
+    /// * `ellipsoid` - Ellipsoid modelling the shape of the Earth
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not a positive, finite number.
+    pub fn with_ellipsoid(step: T, ellipsoid: Ellipsoid) -> Self {
+        assert!(step > T::zero() && step.is_finite());
+
+        let ninety = T::from(90.0).unwrap();
+        let e2 = ellipsoid.eccentricity_squared_as();
+        let bands = (T::from(180.0).unwrap() / step).ceil().to_usize().unwrap();
+        let multipliers = (0..=bands)
+            .map(|i| {
+                let latitude =
+                    (T::from(i).unwrap() * step - ninety).min(ninety);
+                curvature_multipliers(latitude, e2)
+            })
+            .collect();
+
+        Self {
+            step,
+            ellipsoid,
+            multipliers,
+        }
+    }
+
+    /// Returns the width of a latitude band in degrees
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// Returns the latitude of the center of the band that the given latitude
+    /// falls into. Latitudes outside of ±90 degrees are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude
+    pub fn band_latitude(&self, latitude: T) -> T {
+        let ninety = T::from(90.0).unwrap();
+        (T::from(self.band(latitude)).unwrap() * self.step - ninety).min(ninety)
+    }
+
+    /// Returns a ruler for the band that the given latitude falls into. The
+    /// ruler is identical to one created with [`CheapRuler::new`] for the
+    /// latitude returned by [`RulerCache::band_latitude`] and the cache's
+    /// ellipsoid.
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude
+    /// * `distance_unit` - Unit to express distances in
+    pub fn ruler(
+        &self,
+        latitude: T,
+        distance_unit: DistanceUnit,
+    ) -> CheapRuler<T> {
+        let (dkx, dky) = self.multipliers[self.band(latitude)];
+        CheapRuler::from_curvature_multipliers(
+            distance_unit,
+            &self.ellipsoid,
+            dkx,
+            dky,
+        )
+    }
+
+    /// Returns the worst-case relative error that quantizing the given
+    /// latitude to its band adds to distances measured with the ruler. The
+    /// error grows without bound in the bands touching the poles.
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude
+    pub fn quantization_error(&self, latitude: T) -> T {
+        let ninety = T::from(90.0).unwrap();
+        self.band_error(self.band(latitude), ninety)
+    }
+
+    /// Returns the worst-case relative error that quantizing adds to distances
+    /// measured with the cache's rulers, for all latitudes up to the given
+    /// absolute latitude
+    ///
+    /// # Arguments
+    ///
+    /// * `max_latitude` - Largest absolute latitude to consider
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::RulerCache;
+    /// let cache = RulerCache::new(0.1);
+    /// assert!(cache.max_quantization_error(60.0) < 0.002);
+    /// ```
+    pub fn max_quantization_error(&self, max_latitude: T) -> T {
+        let max_latitude = max_latitude.abs();
+        let first = self.band(-max_latitude);
+        let last = self.band(max_latitude);
+
+        (first..=last)
+            .map(|band| self.band_error(band, max_latitude))
+            .fold(T::zero(), T::max)
+    }
+
+    fn band(&self, latitude: T) -> usize {
+        let ninety = T::from(90.0).unwrap();
+        let last = T::from(self.multipliers.len() - 1).unwrap();
+        ((latitude + ninety) / self.step)
+            .round()
+            .max(T::zero())
+            .min(last)
+            .to_usize()
+            .unwrap()
+    }
+
+    /// Returns the largest relative difference between the band's multipliers
+    /// and the exact multipliers anywhere in the band, considering latitudes
+    /// up to `max_latitude` only
+    fn band_error(&self, band: usize, max_latitude: T) -> T {
+        let ninety = T::from(90.0).unwrap();
+        let half_step = self.step / T::from(2.0).unwrap();
+        let center = (T::from(band).unwrap() * self.step - ninety).min(ninety);
+        let (dkx, dky) = self.multipliers[band];
+        let e2 = self.ellipsoid.eccentricity_squared_as();
+
+        // multipliers are monotonic in absolute latitude, so the largest
+        // difference within a band is at one of its edges
+        [center - half_step, center + half_step]
+            .iter()
+            .map(|edge| {
+                let edge = edge.max(-max_latitude).min(max_latitude);
+                let (exact_dkx, exact_dky) = curvature_multipliers(edge, e2);
+                let error_x = (dkx / exact_dkx - T::one()).abs();
+                let error_y = (dky / exact_dky - T::one()).abs();
+                error_x.max(error_y)
+            })
+            .fold(T::zero(), T::max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo_types::point;
+
+    #[test]
+    fn test_ruler_cache_matches_ruler() {
+        let cache = RulerCache::new(0.1);
+
+        for &(latitude, band_latitude) in [
+            (44.7192003, 44.7),
+            (-33.86, -33.9),
+            (0.04, 0.0),
+            (90.0, 90.0),
+        ]
+        .iter()
+        {
+            assert!(
+                (cache.band_latitude(latitude) - band_latitude).abs() < 1e-9
+            );
+            assert_eq!(
+                cache.ruler(latitude, DistanceUnit::Miles),
+                CheapRuler::new(
+                    cache.band_latitude(latitude),
+                    DistanceUnit::Miles
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_ruler_cache_clamps_latitude() {
+        let cache = RulerCache::new(1.0);
+
+        assert_eq!(cache.band_latitude(-95.0), -90.0);
+        assert_eq!(cache.band_latitude(95.0), 90.0);
+    }
+
+    #[test]
+    fn test_ruler_cache_band_centers() {
+        // centers are offset from -90, not from the equator
+        let cache = RulerCache::new(0.7);
+        assert!((cache.band_latitude(0.0) - 0.3).abs() < 1e-9);
+        assert!((cache.band_latitude(-90.0) + 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ruler_cache_quantization_error() {
+        let cache = RulerCache::new(0.1);
+        let error = cache.quantization_error(50.03);
+
+        let a = point!(x: 30.5, y: 50.03);
+        let b = point!(x: 30.51, y: 50.04);
+        let exact = CheapRuler::new(50.03, DistanceUnit::Meters);
+        let cached = cache.ruler(50.03, DistanceUnit::Meters);
+        let actual_error =
+            (cached.distance(&a, &b) / exact.distance(&a, &b) - 1.0).abs();
+
+        assert!(actual_error <= error);
+        assert!(error < 0.002);
+        assert!(cache.max_quantization_error(60.0) >= error);
+        assert!(
+            cache.max_quantization_error(80.0)
+                > cache.max_quantization_error(60.0)
+        );
+    }
+
+    #[test]
+    fn test_ruler_cache_is_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<RulerCache<f64>>();
+    }
+}
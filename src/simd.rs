@@ -0,0 +1,371 @@
+//! Distance and bearing kernels over structure-of-arrays coordinates.
+//!
+//! With the `simd` feature enabled, the `f32` and `f64` kernels process
+//! coordinates in explicit SIMD lanes using the [wide] crate. Without it, or
+//! for the elements that don't fill a whole lane, they fall back to the same
+//! scalar formulas used by the rest of [`CheapRuler`].
+//!
+//! # Tolerance
+//!
+//! Square distances and distances are computed with the same sequence of
+//! operations as [`CheapRuler::square_distance`] and [`CheapRuler::distance`]
+//! and match them to within a relative error of `1e-12` for `f64` and `1e-6`
+//! for `f32`. Bearings use a polynomial `atan2` approximation and match
+//! [`CheapRuler::bearing`] to within `1e-9` degrees for `f64` and `1e-4`
+//! degrees for `f32`. A longitude difference of exactly 180 degrees may be
+//! resolved to the opposite side, which gives a bearing of the opposite sign.
+//!
+//! [wide]: https://docs.rs/wide
+
+use crate::lng_lat::to_point;
+use crate::{long_diff, CheapRuler, LngLat};
+use geo_types::Point;
+use num_traits::Float;
+use std::fmt;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Floating-point types with vectorized structure-of-arrays kernels. This
+/// trait is sealed and implemented for `f32` and `f64`.
+pub trait SimdFloat: Float + fmt::Debug + private::Sealed {
+    #[doc(hidden)]
+    fn square_distances(
+        kx: Self,
+        ky: Self,
+        origin: &Point<Self>,
+        xs: &[Self],
+        ys: &[Self],
+        out: &mut [Self],
+    );
+
+    #[doc(hidden)]
+    fn distances(
+        kx: Self,
+        ky: Self,
+        origin: &Point<Self>,
+        xs: &[Self],
+        ys: &[Self],
+        out: &mut [Self],
+    );
+
+    #[doc(hidden)]
+    fn bearings(
+        kx: Self,
+        ky: Self,
+        origin: &Point<Self>,
+        xs: &[Self],
+        ys: &[Self],
+        out: &mut [Self],
+    );
+}
+
+impl<T> CheapRuler<T>
+where
+    T: SimdFloat,
+{
+    /// Calculates the squares of the approximate distances from an origin
+    /// point to points given as separate longitude and latitude slices,
+    /// writing them into `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin point
+    /// * `xs` - Longitudes of the points
+    /// * `ys` - Latitudes of the points
+    /// * `out` - Output buffer, one square distance per point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs`, `ys` and `out` are not all the same length.
+    pub fn square_distances_soa<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        xs: &[T],
+        ys: &[T],
+        out: &mut [T],
+    ) {
+        assert_soa_lengths(xs, ys, out);
+        T::square_distances(self.kx, self.ky, &to_point(origin), xs, ys, out);
+    }
+
+    /// Calculates the approximate distances from an origin point to points
+    /// given as separate longitude and latitude slices, writing them into
+    /// `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin point
+    /// * `xs` - Longitudes of the points
+    /// * `ys` - Latitudes of the points
+    /// * `out` - Output buffer, one distance per point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs`, `ys` and `out` are not all the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::Point;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let origin = Point::new(14.8901816, 44.7209699);
+    /// let xs = [14.8905188, 14.8901816];
+    /// let ys = [44.7209699, 44.7219699];
+    /// let mut distances = [0.0; 2];
+    /// cr.distances_soa(&origin, &xs, &ys, &mut distances);
+    /// assert!(distances[0] < 38.0);
+    /// ```
+    pub fn distances_soa<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        xs: &[T],
+        ys: &[T],
+        out: &mut [T],
+    ) {
+        assert_soa_lengths(xs, ys, out);
+        T::distances(self.kx, self.ky, &to_point(origin), xs, ys, out);
+    }
+
+    /// Calculates the bearings from an origin point to points given as
+    /// separate longitude and latitude slices, writing them into `out`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Origin point
+    /// * `xs` - Longitudes of the points
+    /// * `ys` - Latitudes of the points
+    /// * `out` - Output buffer, one bearing per point
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs`, `ys` and `out` are not all the same length.
+    pub fn bearings_soa<P: LngLat<T>>(
+        &self,
+        origin: &P,
+        xs: &[T],
+        ys: &[T],
+        out: &mut [T],
+    ) {
+        assert_soa_lengths(xs, ys, out);
+        T::bearings(self.kx, self.ky, &to_point(origin), xs, ys, out);
+    }
+}
+
+fn assert_soa_lengths<T>(xs: &[T], ys: &[T], out: &[T]) {
+    assert_eq!(xs.len(), ys.len());
+    assert_eq!(xs.len(), out.len());
+}
+
+fn scalar_square_distances<T: Float + fmt::Debug>(
+    kx: T,
+    ky: T,
+    origin: &Point<T>,
+    xs: &[T],
+    ys: &[T],
+    out: &mut [T],
+) {
+    for ((&x, &y), d) in xs.iter().zip(ys).zip(out.iter_mut()) {
+        let dx = long_diff(origin.x(), x) * kx;
+        let dy = (origin.y() - y) * ky;
+        *d = dx * dx + dy * dy;
+    }
+}
+
+fn scalar_distances<T: Float + fmt::Debug>(
+    kx: T,
+    ky: T,
+    origin: &Point<T>,
+    xs: &[T],
+    ys: &[T],
+    out: &mut [T],
+) {
+    scalar_square_distances(kx, ky, origin, xs, ys, out);
+    for d in out.iter_mut() {
+        *d = d.sqrt();
+    }
+}
+
+fn scalar_bearings<T: Float + fmt::Debug>(
+    kx: T,
+    ky: T,
+    origin: &Point<T>,
+    xs: &[T],
+    ys: &[T],
+    out: &mut [T],
+) {
+    for ((&x, &y), b) in xs.iter().zip(ys).zip(out.iter_mut()) {
+        let dx = long_diff(x, origin.x()) * kx;
+        let dy = (y - origin.y()) * ky;
+        *b = dx.atan2(dy).to_degrees();
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+macro_rules! impl_simd_float {
+    ($t:ty) => {
+        impl SimdFloat for $t {
+            fn square_distances(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                scalar_square_distances(kx, ky, origin, xs, ys, out);
+            }
+
+            fn distances(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                scalar_distances(kx, ky, origin, xs, ys, out);
+            }
+
+            fn bearings(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                scalar_bearings(kx, ky, origin, xs, ys, out);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "simd"))]
+impl_simd_float!(f32);
+#[cfg(not(feature = "simd"))]
+impl_simd_float!(f64);
+
+#[cfg(feature = "simd")]
+macro_rules! impl_simd_float {
+    ($t:ty, $v:ty, $lanes:expr) => {
+        impl SimdFloat for $t {
+            fn square_distances(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                let n = xs.len() - xs.len() % $lanes;
+                let (kx_v, ky_v) = (<$v>::splat(kx), <$v>::splat(ky));
+                let ox = <$v>::splat(origin.x());
+                let oy = <$v>::splat(origin.y());
+
+                for ((x, y), d) in xs[..n]
+                    .chunks_exact($lanes)
+                    .zip(ys[..n].chunks_exact($lanes))
+                    .zip(out[..n].chunks_exact_mut($lanes))
+                {
+                    let dx = Self::long_diff_lanes(ox - Self::load(x)) * kx_v;
+                    let dy = (oy - Self::load(y)) * ky_v;
+                    d.copy_from_slice(&(dx * dx + dy * dy).to_array());
+                }
+
+                let (xs, ys, out) = (&xs[n..], &ys[n..], &mut out[n..]);
+                scalar_square_distances(kx, ky, origin, xs, ys, out);
+            }
+
+            fn distances(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                let n = xs.len() - xs.len() % $lanes;
+                let (kx_v, ky_v) = (<$v>::splat(kx), <$v>::splat(ky));
+                let ox = <$v>::splat(origin.x());
+                let oy = <$v>::splat(origin.y());
+
+                for ((x, y), d) in xs[..n]
+                    .chunks_exact($lanes)
+                    .zip(ys[..n].chunks_exact($lanes))
+                    .zip(out[..n].chunks_exact_mut($lanes))
+                {
+                    let dx = Self::long_diff_lanes(ox - Self::load(x)) * kx_v;
+                    let dy = (oy - Self::load(y)) * ky_v;
+                    d.copy_from_slice(&(dx * dx + dy * dy).sqrt().to_array());
+                }
+
+                let (xs, ys, out) = (&xs[n..], &ys[n..], &mut out[n..]);
+                scalar_distances(kx, ky, origin, xs, ys, out);
+            }
+
+            fn bearings(
+                kx: Self,
+                ky: Self,
+                origin: &Point<Self>,
+                xs: &[Self],
+                ys: &[Self],
+                out: &mut [Self],
+            ) {
+                let n = xs.len() - xs.len() % $lanes;
+                let (kx_v, ky_v) = (<$v>::splat(kx), <$v>::splat(ky));
+                let ox = <$v>::splat(origin.x());
+                let oy = <$v>::splat(origin.y());
+                let to_degrees = <$v>::splat(<$t>::to_degrees(1.0));
+
+                for ((x, y), b) in xs[..n]
+                    .chunks_exact($lanes)
+                    .zip(ys[..n].chunks_exact($lanes))
+                    .zip(out[..n].chunks_exact_mut($lanes))
+                {
+                    let dx = Self::long_diff_lanes(Self::load(x) - ox) * kx_v;
+                    let dy = (Self::load(y) - oy) * ky_v;
+                    b.copy_from_slice(&(dx.atan2(dy) * to_degrees).to_array());
+                }
+
+                let (xs, ys, out) = (&xs[n..], &ys[n..], &mut out[n..]);
+                scalar_bearings(kx, ky, origin, xs, ys, out);
+            }
+        }
+
+        impl Lanes for $t {
+            type Vector = $v;
+
+            fn load(chunk: &[Self]) -> $v {
+                let mut array = [0.0; $lanes];
+                array.copy_from_slice(chunk);
+                <$v>::new(array)
+            }
+
+            fn long_diff_lanes(diff: $v) -> $v {
+                let three_sixty = <$v>::splat(360.0);
+                diff - ((diff / three_sixty).round() * three_sixty)
+            }
+        }
+    };
+}
+
+/// Lane vector helpers mirroring the scalar formulas
+#[cfg(feature = "simd")]
+trait Lanes: Sized {
+    type Vector;
+
+    fn load(chunk: &[Self]) -> Self::Vector;
+
+    fn long_diff_lanes(diff: Self::Vector) -> Self::Vector;
+}
+
+#[cfg(feature = "simd")]
+impl_simd_float!(f32, wide::f32x8, 8);
+#[cfg(feature = "simd")]
+impl_simd_float!(f64, wide::f64x4, 4);
@@ -0,0 +1,436 @@
+use crate::{CheapRuler, LocalFrame};
+use geo_types::{
+    Coordinate, LineString, MultiLineString, MultiPolygon, Polygon,
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Simplifies a geometry with the Douglas–Peucker algorithm, using a
+/// tolerance in a [`CheapRuler`]'s units, shaped like the geo crate's
+/// `Simplify`
+///
+/// Polygon rings keep at least four coordinates, so polygons remain valid
+/// however large the tolerance is.
+pub trait CheapSimplify<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the simplified geometry
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure the tolerance with
+    /// * `tolerance` - Largest distance of a removed coordinate from the
+    ///   simplified line
+    fn cheap_simplify(&self, ruler: &CheapRuler<T>, tolerance: T) -> Self;
+
+    /// Returns the simplified geometry, keeping additional coordinates where
+    /// simplifying would make its lines or rings intersect
+    ///
+    /// # Arguments
+    ///
+    /// * `ruler` - Ruler to measure the tolerance with
+    /// * `tolerance` - Largest distance of a removed coordinate from the
+    ///   simplified line
+    fn cheap_simplify_preserve_topology(
+        &self,
+        ruler: &CheapRuler<T>,
+        tolerance: T,
+    ) -> Self;
+}
+
+impl<T> CheapSimplify<T> for LineString<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_simplify(&self, ruler: &CheapRuler<T>, tolerance: T) -> Self {
+        simplify_lines(ruler, &[(self, false)], tolerance, false).remove(0)
+    }
+
+    fn cheap_simplify_preserve_topology(
+        &self,
+        ruler: &CheapRuler<T>,
+        tolerance: T,
+    ) -> Self {
+        simplify_lines(ruler, &[(self, false)], tolerance, true).remove(0)
+    }
+}
+
+impl<T> CheapSimplify<T> for MultiLineString<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_simplify(&self, ruler: &CheapRuler<T>, tolerance: T) -> Self {
+        let lines: Vec<_> = self.0.iter().map(|line| (line, false)).collect();
+        MultiLineString(simplify_lines(ruler, &lines, tolerance, false))
+    }
+
+    fn cheap_simplify_preserve_topology(
+        &self,
+        ruler: &CheapRuler<T>,
+        tolerance: T,
+    ) -> Self {
+        let lines: Vec<_> = self.0.iter().map(|line| (line, false)).collect();
+        MultiLineString(simplify_lines(ruler, &lines, tolerance, true))
+    }
+}
+
+impl<T> CheapSimplify<T> for Polygon<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_simplify(&self, ruler: &CheapRuler<T>, tolerance: T) -> Self {
+        simplify_polygons(ruler, std::slice::from_ref(self), tolerance, false)
+            .remove(0)
+    }
+
+    fn cheap_simplify_preserve_topology(
+        &self,
+        ruler: &CheapRuler<T>,
+        tolerance: T,
+    ) -> Self {
+        simplify_polygons(ruler, std::slice::from_ref(self), tolerance, true)
+            .remove(0)
+    }
+}
+
+impl<T> CheapSimplify<T> for MultiPolygon<T>
+where
+    T: Float + fmt::Debug,
+{
+    fn cheap_simplify(&self, ruler: &CheapRuler<T>, tolerance: T) -> Self {
+        MultiPolygon(simplify_polygons(ruler, &self.0, tolerance, false))
+    }
+
+    fn cheap_simplify_preserve_topology(
+        &self,
+        ruler: &CheapRuler<T>,
+        tolerance: T,
+    ) -> Self {
+        MultiPolygon(simplify_polygons(ruler, &self.0, tolerance, true))
+    }
+}
+
+/// Simplifies the rings of the polygons together, so that with topology
+/// preserved, rings of different polygons don't intersect either
+fn simplify_polygons<T>(
+    ruler: &CheapRuler<T>,
+    polygons: &[Polygon<T>],
+    tolerance: T,
+    preserve_topology: bool,
+) -> Vec<Polygon<T>>
+where
+    T: Float + fmt::Debug,
+{
+    let rings: Vec<_> = polygons
+        .iter()
+        .flat_map(|p| std::iter::once(p.exterior()).chain(p.interiors()))
+        .map(|ring| (ring, true))
+        .collect();
+    let mut rings =
+        simplify_lines(ruler, &rings, tolerance, preserve_topology).into_iter();
+
+    polygons
+        .iter()
+        .map(|p| {
+            let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+            let interiors = rings.by_ref().take(p.interiors().len()).collect();
+            Polygon::new(exterior, interiors)
+        })
+        .collect()
+}
+
+/// Simplifies each of the lines, given with whether it is a polygon ring
+fn simplify_lines<T>(
+    ruler: &CheapRuler<T>,
+    lines: &[(&LineString<T>, bool)],
+    tolerance: T,
+    preserve_topology: bool,
+) -> Vec<LineString<T>>
+where
+    T: Float + fmt::Debug,
+{
+    let mut kept: Vec<_> = lines
+        .iter()
+        .map(|(line, ring)| douglas_peucker(ruler, &line.0, tolerance, *ring))
+        .collect();
+
+    if preserve_topology {
+        restore_topology(ruler, lines, &mut kept);
+    }
+
+    lines
+        .iter()
+        .zip(kept)
+        .map(|((line, _), kept)| {
+            line.0
+                .iter()
+                .zip(kept)
+                .filter(|(_, kept)| *kept)
+                .map(|(&c, _)| c)
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns which coordinates of the line are kept by the Douglas–Peucker
+/// algorithm with the given tolerance
+fn douglas_peucker<T>(
+    ruler: &CheapRuler<T>,
+    coords: &[Coordinate<T>],
+    tolerance: T,
+    ring: bool,
+) -> Vec<bool>
+where
+    T: Float + fmt::Debug,
+{
+    let n = coords.len();
+    if n <= 2 || (ring && n <= 4) {
+        return vec![true; n];
+    }
+
+    let mut kept = vec![false; n];
+    kept[0] = true;
+    kept[n - 1] = true;
+
+    let mut stack = vec![(0, n - 1)];
+    while let Some((first, last)) = stack.pop() {
+        if let Some((index, dist)) = farthest(ruler, coords, first, last) {
+            if dist > tolerance {
+                kept[index] = true;
+                stack.push((first, index));
+                stack.push((index, last));
+            }
+        }
+    }
+
+    if ring {
+        while kept.iter().filter(|&&k| k).count() < 4 {
+            let spans = segments(&kept)
+                .filter_map(|(i, j)| farthest(ruler, coords, i, j));
+            match max_distance(spans) {
+                Some((index, _)) => kept[index] = true,
+                None => break,
+            }
+        }
+    }
+
+    kept
+}
+
+/// Returns the index and distance of the coordinate between first and last
+/// that is farthest from the segment joining them, if there is one
+fn farthest<T>(
+    ruler: &CheapRuler<T>,
+    coords: &[Coordinate<T>],
+    first: usize,
+    last: usize,
+) -> Option<(usize, T)>
+where
+    T: Float + fmt::Debug,
+{
+    let (start, end) = (&coords[first], &coords[last]);
+    max_distance(
+        (first + 1..last).map(|i| {
+            (i, ruler.point_to_segment_distance(&coords[i], start, end))
+        }),
+    )
+}
+
+/// Returns the first of the indexed distances with the largest distance
+fn max_distance<T, I>(distances: I) -> Option<(usize, T)>
+where
+    T: Float,
+    I: Iterator<Item = (usize, T)>,
+{
+    distances.fold(None, |best, (i, dist)| match best {
+        Some((_, d)) if d >= dist => best,
+        _ => Some((i, dist)),
+    })
+}
+
+/// Returns the start and end indices of the segments between kept
+/// coordinates
+fn segments(kept: &[bool]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut indices = kept.iter().enumerate().filter(|(_, k)| **k);
+    let mut prev = indices.next().map(|(i, _)| i);
+    indices.map(move |(j, _)| {
+        let i = prev.replace(j).unwrap_or(j);
+        (i, j)
+    })
+}
+
+/// Keeps the farthest removed coordinate of simplified segments that
+/// intersect other simplified segments, until none intersect or the
+/// intersecting segments can't be refined further
+///
+/// Each pass sweeps the segments sorted by easting, which is quadratic in the
+/// worst case of many segments overlapping in easting.
+fn restore_topology<T>(
+    ruler: &CheapRuler<T>,
+    lines: &[(&LineString<T>, bool)],
+    kept: &mut [Vec<bool>],
+) where
+    T: Float + fmt::Debug,
+{
+    let frame = match lines.iter().find_map(|(line, _)| line.0.first()) {
+        Some(origin) => LocalFrame::new(ruler, origin),
+        None => return,
+    };
+    let projected: Vec<Vec<Coordinate<T>>> = lines
+        .iter()
+        .map(|(line, _)| line.0.iter().map(|c| frame.project(c).0).collect())
+        .collect();
+
+    loop {
+        // sweep the segments by their easting, only comparing the ones whose
+        // eastings overlap
+        let mut segments: Vec<(usize, usize, usize, T, T)> = kept
+            .iter()
+            .enumerate()
+            .flat_map(|(k, kept)| segments(kept).map(move |(i, j)| (k, i, j)))
+            .map(|(k, i, j)| {
+                let (a, b) = (projected[k][i].x, projected[k][j].x);
+                (k, i, j, a.min(b), a.max(b))
+            })
+            .collect();
+        segments.sort_by(|a, b| {
+            a.3.partial_cmp(&b.3).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut splits = vec![];
+        for (a, &(ka, ia, ja, _, max_x)) in segments.iter().enumerate() {
+            for &(kb, ib, jb, min_x, _) in &segments[a + 1..] {
+                if min_x > max_x {
+                    break;
+                }
+                let (pa, pb) = (&projected[ka], &projected[kb]);
+                if intersects(pa[ia], pa[ja], pb[ib], pb[jb]) {
+                    splits.push((ka, ia, ja));
+                    splits.push((kb, ib, jb));
+                }
+            }
+        }
+
+        let mut refined = false;
+        for (k, i, j) in splits {
+            let (line, _) = lines[k];
+            if let Some((index, _)) = farthest(ruler, &line.0, i, j) {
+                refined |= !kept[k][index];
+                kept[k][index] = true;
+            }
+        }
+
+        if !refined {
+            break;
+        }
+    }
+}
+
+/// Returns true if segments p and q have a point in common other than an
+/// endpoint they share
+fn intersects<T: Float + fmt::Debug>(
+    p1: Coordinate<T>,
+    p2: Coordinate<T>,
+    q1: Coordinate<T>,
+    q2: Coordinate<T>,
+) -> bool {
+    if p1 == q1 || p1 == q2 || p2 == q1 || p2 == q2 {
+        return false;
+    }
+
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    let zero = T::zero();
+
+    if ((d1 > zero && d2 < zero) || (d1 < zero && d2 > zero))
+        && ((d3 > zero && d4 < zero) || (d3 < zero && d4 > zero))
+    {
+        return true;
+    }
+
+    (d1 == zero && on_segment(q1, q2, p1))
+        || (d2 == zero && on_segment(q1, q2, p2))
+        || (d3 == zero && on_segment(p1, p2, q1))
+        || (d4 == zero && on_segment(p1, p2, q2))
+}
+
+fn orientation<T: Float + fmt::Debug>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+) -> T {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Returns true if c, collinear with a and b, lies between them
+fn on_segment<T: Float + fmt::Debug>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+) -> bool {
+    c.x >= a.x.min(b.x)
+        && c.x <= a.x.max(b.x)
+        && c.y >= a.y.min(b.y)
+        && c.y <= a.y.max(b.y)
+}
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns a simplified copy of the geometry, removing coordinates that
+    /// are within the tolerance of the simplified line using the
+    /// Douglas–Peucker algorithm. See [`CheapSimplify`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Line string, polygon or a collection of them
+    /// * `tolerance` - Largest distance of a removed coordinate from the
+    ///   simplified line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.0305, 50.47),
+    ///     (-67.031, 50.534),
+    /// ].into();
+    /// let simplified = cr.simplify(&line_string, 50.0);
+    /// assert_eq!(simplified.0.len(), 2);
+    /// ```
+    pub fn simplify<G: CheapSimplify<T>>(
+        &self,
+        geometry: &G,
+        tolerance: T,
+    ) -> G {
+        geometry.cheap_simplify(self, tolerance)
+    }
+
+    /// Returns a simplified copy of the geometry like
+    /// [`CheapRuler::simplify`], keeping additional coordinates where
+    /// simplifying would make its lines or rings intersect
+    ///
+    /// Checking for intersections takes a sweep over the simplified segments
+    /// per refinement, so this is slower than [`CheapRuler::simplify`],
+    /// especially for long lines that run north to south.
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Line string, polygon or a collection of them
+    /// * `tolerance` - Largest distance of a removed coordinate from the
+    ///   simplified line
+    pub fn simplify_preserve_topology<G: CheapSimplify<T>>(
+        &self,
+        geometry: &G,
+        tolerance: T,
+    ) -> G {
+        geometry.cheap_simplify_preserve_topology(self, tolerance)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{long_diff, same_point, CheapRuler, LngLat, Side};
+use geo_types::{LineString, Point};
+use num_traits::Float;
+use std::fmt;
+
+/// Position relative to a line, given as the distance along the line
+/// (station) and the signed perpendicular distance from it (offset)
+///
+/// Offsets are positive to the left of the line and negative to its right,
+/// looking in the direction of the line.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StationOffset<T>
+where
+    T: Float + fmt::Debug,
+{
+    station: T,
+    offset: T,
+}
+
+impl<T> StationOffset<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Creates a position relative to a line
+    ///
+    /// # Arguments
+    ///
+    /// * `station` - Distance along the line
+    /// * `offset` - Distance from the line, positive to its left
+    pub fn new(station: T, offset: T) -> Self {
+        Self { station, offset }
+    }
+
+    /// Distance along the line
+    pub fn station(&self) -> T {
+        self.station
+    }
+
+    /// Distance from the line, positive to its left and negative to its right
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+}
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns the station and the signed offset of a point relative to the
+    /// line, measured to the closest point on the line. This is the inverse
+    /// of [`CheapRuler::from_station_offset`] for points alongside the line.
+    ///
+    /// Returns `None` if the line is empty, or if the point lies beyond either
+    /// end of the line, where the closest point on the line is clamped to the
+    /// end and no station and offset lead back to the point.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `point` - Point to locate relative to the line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let centerline: LineString<f64> =
+    ///     vec![(-67.031, 50.458), (-67.031, 50.534)].into();
+    /// let defect = cr.offset(&(-67.031, 50.5), -3.5, 0.0);
+    ///
+    /// let position = cr.station_offset(&centerline, &defect).unwrap();
+    /// assert!((position.offset() - 3.5).abs() < 1e-6);
+    /// ```
+    pub fn station_offset<P: LngLat<T>>(
+        &self,
+        line: &LineString<T>,
+        point: &P,
+    ) -> Option<StationOffset<T>> {
+        let pol = self.point_on_line(line, point)?;
+        let (first, last) = (Point(*line.0.first()?), Point(*line.0.last()?));
+        let closest = pol.point();
+
+        // the distance to the point along the direction of its segment, which
+        // is only zero for points alongside the line
+        let bearing = pol.bearing().to_radians();
+        let along =
+            long_diff(point.lng(), closest.x()) * self.kx * bearing.sin()
+                + (point.lat() - closest.y()) * self.ky * bearing.cos();
+        let tolerance = pol.distance() * T::epsilon().sqrt();
+        if (along < -tolerance && same_point(&closest, &first))
+            || (along > tolerance && same_point(&closest, &last))
+        {
+            return None;
+        }
+
+        let offset = match pol.side() {
+            Side::Left => pol.distance(),
+            Side::Right => -pol.distance(),
+            Side::On => T::zero(),
+        };
+        Some(StationOffset::new(pol.along(), offset))
+    }
+
+    /// Returns the point at a station along the line, moved perpendicular to
+    /// the line by the signed offset, positive to the left
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line
+    /// * `station` - Distance along the line
+    /// * `offset` - Distance from the line, positive to its left
+    pub fn from_station_offset(
+        &self,
+        line: &LineString<T>,
+        station: T,
+        offset: T,
+    ) -> Option<Point<T>> {
+        let pol = self.locate_along(line, station)?;
+        let bearing = pol.bearing().to_radians();
+        Some(self.offset(
+            &pol.point(),
+            -bearing.cos() * offset,
+            bearing.sin() * offset,
+        ))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{long_diff, CheapMapCoords, CheapRuler, LngLat, LocalFrame};
+use geo_types::{Coordinate, Point};
+use num_traits::Float;
+use std::fmt;
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns a copy of the geometry moved by easting and northing offsets
+    /// (in ruler units). See [`CheapRuler::offset`].
+    ///
+    /// Unlike [`CheapRuler::offset`], longitudes aren't wrapped into ±180
+    /// degrees, so the geometry keeps its longitude frame and stays
+    /// contiguous across the antimeridian.
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to move
+    /// * `dx` - easting
+    /// * `dy` - northing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit};
+    /// use geo_types::Polygon;
+    /// let cr = CheapRuler::new(44.7192003, DistanceUnit::Meters);
+    /// let footprint: Polygon<f64> = Polygon::new(
+    ///     vec![
+    ///         (14.8901816, 44.7209699),
+    ///         (14.8902816, 44.7209699),
+    ///         (14.8902816, 44.7210699),
+    ///         (14.8901816, 44.7209699),
+    ///     ]
+    ///     .into(),
+    ///     vec![],
+    /// );
+    /// let moved = cr.translate(&footprint, 10.0, -5.0);
+    /// assert!((cr.area(&moved) - cr.area(&footprint)).abs() < 1e-6);
+    /// ```
+    pub fn translate<G: CheapMapCoords<T>>(
+        &self,
+        geometry: &G,
+        dx: T,
+        dy: T,
+    ) -> G {
+        let (dx, dy) = (dx / self.kx, dy / self.ky);
+        geometry.cheap_map_coords(&|c| coord!(x: c.x + dx, y: c.y + dy))
+    }
+
+    /// Returns a copy of the geometry moved by a distance in the direction of
+    /// a bearing. See [`CheapRuler::destination`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to move
+    /// * `dist` - Distance
+    /// * `bearing` - Bearing
+    pub fn translate_by<G: CheapMapCoords<T>>(
+        &self,
+        geometry: &G,
+        dist: T,
+        bearing: T,
+    ) -> G {
+        let a = bearing.to_radians();
+        self.translate(geometry, a.sin() * dist, a.cos() * dist)
+    }
+
+    /// Returns a copy of the geometry rotated clockwise around a pivot point.
+    /// Longitudes keep the geometry's frame like [`CheapRuler::translate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to rotate
+    /// * `pivot` - Point to rotate around
+    /// * `angle` - Angle in degrees, clockwise like bearings
+    pub fn rotate<G, P>(&self, geometry: &G, pivot: &P, angle: T) -> G
+    where
+        G: CheapMapCoords<T>,
+        P: LngLat<T>,
+    {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        self.map_local(geometry, pivot, |p| {
+            Point::new(p.x() * cos + p.y() * sin, p.y() * cos - p.x() * sin)
+        })
+    }
+
+    /// Returns a copy of the geometry scaled about a pivot point. Longitudes
+    /// keep the geometry's frame like [`CheapRuler::translate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - Geometry to scale
+    /// * `pivot` - Point to scale about
+    /// * `factor` - Scale factor applied to distances from the pivot
+    pub fn scale<G, P>(&self, geometry: &G, pivot: &P, factor: T) -> G
+    where
+        G: CheapMapCoords<T>,
+        P: LngLat<T>,
+    {
+        self.map_local(geometry, pivot, |p| p * factor)
+    }
+
+    /// Applies a planar transformation to each coordinate of the geometry in
+    /// a local frame around the pivot point, keeping the longitude frame of
+    /// each coordinate
+    fn map_local<G, P, F>(&self, geometry: &G, pivot: &P, f: F) -> G
+    where
+        G: CheapMapCoords<T>,
+        P: LngLat<T>,
+        F: Fn(Point<T>) -> Point<T>,
+    {
+        let frame = LocalFrame::new(self, pivot);
+        geometry.cheap_map_coords(&|c: Coordinate<T>| {
+            let p = frame.unproject(&f(frame.project(&c)));
+            coord! { x: c.x + long_diff(p.x(), c.x), y: p.y() }
+        })
+    }
+}
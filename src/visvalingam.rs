@@ -0,0 +1,201 @@
+use crate::{long_diff, CheapRuler};
+use geo_types::{Coordinate, LineString};
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+/// When to stop removing vertices in [`CheapRuler::simplify_vw`]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum VwTarget<T> {
+    /// Remove every vertex with an effective area up to this area, in the
+    /// ruler's units squared
+    Area(T),
+    /// Remove the least important vertices until at most this many remain.
+    /// The first and last vertices are always kept, so counts below two
+    /// still keep two vertices of a line that has them.
+    VertexCount(usize),
+}
+
+/// A line simplified with [`CheapRuler::simplify_vw`], along with the
+/// indices of the vertices it kept from the original line
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct VwSimplified<T>
+where
+    T: Float + fmt::Debug,
+{
+    line: LineString<T>,
+    indices: Vec<usize>,
+}
+
+impl<T> VwSimplified<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Simplified line
+    pub fn line(&self) -> &LineString<T> {
+        &self.line
+    }
+
+    /// Indices of the kept vertices in the original line, in ascending order
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the simplified line, discarding the indices
+    pub fn into_line(self) -> LineString<T> {
+        self.line
+    }
+}
+
+/// Vertex in the queue ordered by smallest area first
+struct Candidate<T> {
+    area: T,
+    index: usize,
+    version: usize,
+}
+
+impl<T: Float> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<T> CheapRuler<T>
+where
+    T: Float + fmt::Debug,
+{
+    /// Returns a simplified copy of the line using the Visvalingam–Whyatt
+    /// algorithm, which repeatedly removes the vertex forming the triangle
+    /// of smallest area with its neighbours
+    ///
+    /// Areas are measured in the ruler's units squared, the same way as
+    /// [`CheapRuler::area`]. A vertex's effective area is never smaller than
+    /// that of a vertex removed before it, so vertices are removed in order
+    /// of importance. The first and last vertices are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line to simplify
+    /// * `target` - Largest effective area to remove, or the number of
+    ///   vertices to keep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheap_ruler::{CheapRuler, DistanceUnit, VwTarget};
+    /// use geo_types::LineString;
+    /// let cr = CheapRuler::new(50.458, DistanceUnit::Meters);
+    /// let line_string: LineString<f64> = vec![
+    ///     (-67.031, 50.458),
+    ///     (-67.0305, 50.47),
+    ///     (-67.031, 50.534),
+    ///     (-66.929, 50.534),
+    /// ].into();
+    /// let simplified = cr.simplify_vw(&line_string, VwTarget::VertexCount(3));
+    /// assert_eq!(simplified.indices(), &[0, 2, 3]);
+    /// ```
+    pub fn simplify_vw(
+        &self,
+        line: &LineString<T>,
+        target: VwTarget<T>,
+    ) -> VwSimplified<T> {
+        let coords = &line.0;
+        let n = coords.len();
+        let mut prev: Vec<usize> =
+            (0..n).map(|i| i.saturating_sub(1)).collect();
+        let mut next: Vec<usize> = (1..=n).collect();
+        let mut versions = vec![0; n];
+        let mut kept = vec![true; n];
+        let mut remaining = n;
+
+        let mut queue: BinaryHeap<_> = (1..n.saturating_sub(1))
+            .map(|i| Candidate {
+                area: self.triangle_area(
+                    &coords[i - 1],
+                    &coords[i],
+                    &coords[i + 1],
+                ),
+                index: i,
+                version: 0,
+            })
+            .collect();
+
+        while let Some(candidate) = queue.pop() {
+            let i = candidate.index;
+            if candidate.version != versions[i] {
+                continue;
+            }
+
+            let done = match target {
+                VwTarget::Area(max_area) => candidate.area > max_area,
+                VwTarget::VertexCount(count) => remaining <= count,
+            };
+            if done {
+                break;
+            }
+
+            kept[i] = false;
+            remaining -= 1;
+            let (p, q) = (prev[i], next[i]);
+            next[p] = q;
+            prev[q] = p;
+
+            // neighbours that aren't endpoints get a new triangle
+            for &j in [p, q].iter() {
+                if j == 0 || j == n - 1 {
+                    continue;
+                }
+                let area = self.triangle_area(
+                    &coords[prev[j]],
+                    &coords[j],
+                    &coords[next[j]],
+                );
+                versions[j] += 1;
+                queue.push(Candidate {
+                    area: area.max(candidate.area),
+                    index: j,
+                    version: versions[j],
+                });
+            }
+        }
+
+        let indices: Vec<usize> = (0..n).filter(|&i| kept[i]).collect();
+        VwSimplified {
+            line: indices.iter().map(|&i| coords[i]).collect(),
+            indices,
+        }
+    }
+
+    /// Returns the area of the triangle with the given vertices
+    fn triangle_area(
+        &self,
+        a: &Coordinate<T>,
+        b: &Coordinate<T>,
+        c: &Coordinate<T>,
+    ) -> T {
+        let (bx, by) = (long_diff(b.x, a.x) * self.kx, (b.y - a.y) * self.ky);
+        let (cx, cy) = (long_diff(c.x, a.x) * self.kx, (c.y - a.y) * self.ky);
+        (bx * cy - by * cx).abs() / T::from(2.0).unwrap()
+    }
+}
@@ -2,6 +2,7 @@
 #![allow(deprecated)]
 
 extern crate cheap_ruler;
+extern crate geo;
 #[macro_use]
 extern crate geo_types;
 #[macro_use]
@@ -11,11 +12,22 @@ extern crate lazy_static;
 mod common;
 mod fixtures;
 
-use cheap_ruler::{CheapRuler, DistanceUnit, Rect};
-pub use common::*;
+use cheap_ruler::{
+    CheapArea, CheapBearing, CheapDestination, CheapDistance, CheapLength,
+    CheapMapCoords, CheapRuler, CheapSimplify, DistanceUnit, Ellipsoid, Error,
+    Geodesic, Haversine, LocalFrame, MeasuredLine, Metric, Rect, Side,
+    VwTarget,
+};
 use fixtures::lines::{LINES, POINTS};
 use fixtures::turf;
-use geo_types::{Coordinate, LineString, Polygon};
+use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::map_coords::MapCoords;
+use geo::algorithm::simplify::Simplify;
+use geo::algorithm::vincenty_distance::VincentyDistance;
+use geo_types::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Triangle,
+};
 
 #[test]
 fn test_distance() {
@@ -41,6 +53,96 @@ fn test_distance_over_dateline() {
     assert_eq_err!(29.05, actual, 0.001, "Distance over dateline within 0.1%");
 }
 
+#[test]
+fn test_area_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let polygon = polygon![
+        (x: 179.9, y: 32.7),
+        (x: -179.9, y: 32.7),
+        (x: -179.9, y: 32.9),
+        (x: 179.9, y: 32.9),
+    ];
+    let shifted = polygon![
+        (x: 179.9, y: 32.7),
+        (x: 180.1, y: 32.7),
+        (x: 180.1, y: 32.9),
+        (x: 179.9, y: 32.9),
+    ];
+
+    assert_eq_err!(ruler.area(&shifted), ruler.area(&polygon), 1e-9);
+    assert_eq_err!(18.7 * 22.2, ruler.area(&polygon), 0.01);
+}
+
+#[test]
+fn test_destination_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let p0 = point!(x: 179.9, y: 32.8);
+    let actual = ruler.destination(&p0, 18.7, 90.0);
+
+    assert!(actual.x() < -179.8 && actual.x() > -180.0);
+    assert_eq_err!(18.7, ruler.distance(&p0, &actual), 1e-9);
+}
+
+#[test]
+fn test_along_over_dateline() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![(x: 179.9, y: 32.8), (x: -179.9, y: 32.8)];
+
+    let actual = ruler.along(&line, ruler.line_distance(&line) * 0.75);
+    let actual = actual.expect("Non-empty line string given");
+
+    assert_eq_err!(-179.95, actual.x(), 1e-9);
+    assert_eq!(32.8, actual.y());
+}
+
+#[test]
+fn test_unwrapped_over_dateline() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![(x: 179.0, y: 32.8), (x: 181.0, y: 32.8)];
+    let length = ruler.line_distance(&line);
+
+    let actual = ruler.along(&line, length * 0.75).unwrap();
+    assert_eq_err!(180.5, actual.x(), 1e-9);
+
+    let slice = ruler.line_slice_along(length * 0.05, length * 0.8, &line);
+    assert_eq_err!(179.1, slice[0].x, 1e-9);
+    assert_eq_err!(180.6, slice[1].x, 1e-9);
+
+    let p0 = point!(x: 180.5, y: 32.8);
+    let actual = ruler.destination(&p0, 18.7, 90.0);
+    assert!(actual.x() > 180.5);
+    assert_eq!(p0, ruler.offset(&p0, 0.0, 0.0));
+}
+
+#[test]
+fn test_line_slice_over_dateline() {
+    let ruler = fixtures::ruler_km();
+    let line = line_string![
+        (x: 179.8, y: 32.8),
+        (x: -180.0, y: 32.8),
+        (x: -179.8, y: 32.8),
+    ];
+
+    let start = point!(x: 180.0, y: 32.9);
+    let stop = point!(x: -179.9, y: 32.7);
+    let slice = ruler.line_slice(&start, &stop, &line);
+
+    assert_eq!(slice.0.len(), 2);
+    assert_eq!(slice[0].x.abs(), 180.0);
+    assert_eq_err!(-179.9, slice[1].x, 1e-9);
+
+    let start = point!(x: 179.85, y: 32.9);
+    let stop = point!(x: -179.85, y: 32.7);
+    let slice = ruler.line_slice(&start, &stop, &line);
+    let expected = ruler
+        .distance(&point!(x: 179.85, y: 32.8), &point!(x: -179.85, y: 32.8));
+
+    assert_eq!(slice.0.len(), 3);
+    assert_eq_err!(expected, ruler.line_distance(&slice), 1e-9);
+}
+
 #[test]
 fn test_distance_miles() {
     let ruler = fixtures::ruler_km();
@@ -55,6 +157,123 @@ fn test_distance_miles() {
     assert_eq_err!(1.609344, d / d2, 1e-12);
 }
 
+#[test]
+fn test_distances() {
+    let ruler = fixtures::ruler_km();
+
+    let mut actual = vec![0.0; POINTS.len()];
+    ruler.distances(&POINTS[0], &POINTS, &mut actual);
+
+    for i in 0..POINTS.len() {
+        assert_eq!(ruler.distance(&POINTS[0], &POINTS[i]), actual[i]);
+    }
+}
+
+#[test]
+fn test_pairwise_distances() {
+    let ruler = fixtures::ruler_km();
+
+    let n = POINTS.len() - 1;
+    let mut actual = vec![0.0; n];
+    ruler.pairwise_distances(&POINTS[..n], &POINTS[1..], &mut actual);
+
+    for i in 0..n {
+        assert_eq!(ruler.distance(&POINTS[i], &POINTS[i + 1]), actual[i]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_pairwise_distances_length_mismatch() {
+    let ruler = fixtures::ruler_km();
+
+    let mut out = vec![0.0; 2];
+    ruler.pairwise_distances(&POINTS[..2], &POINTS[..3], &mut out);
+}
+
+#[test]
+fn test_distance_matrix() {
+    let ruler = fixtures::ruler_km();
+
+    let from = &POINTS[..5];
+    let to = &POINTS[5..12];
+    let mut actual = vec![0.0; from.len() * to.len()];
+    ruler.distance_matrix(from, to, &mut actual);
+
+    for i in 0..from.len() {
+        for j in 0..to.len() {
+            assert_eq!(
+                ruler.distance(&from[i], &to[j]),
+                actual[i * to.len() + j]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_distances_soa() {
+    let ruler = fixtures::ruler_km();
+
+    let xs: Vec<f64> = POINTS.iter().map(|p| p.x()).collect();
+    let ys: Vec<f64> = POINTS.iter().map(|p| p.y()).collect();
+    let mut square_distances = vec![0.0; POINTS.len()];
+    let mut distances = vec![0.0; POINTS.len()];
+    let mut bearings = vec![0.0; POINTS.len()];
+    ruler.square_distances_soa(&POINTS[0], &xs, &ys, &mut square_distances);
+    ruler.distances_soa(&POINTS[0], &xs, &ys, &mut distances);
+    ruler.bearings_soa(&POINTS[0], &xs, &ys, &mut bearings);
+
+    for i in 1..POINTS.len() {
+        let expected = ruler.square_distance(&POINTS[0], &POINTS[i]);
+        assert_eq_err!(expected, square_distances[i], 1e-12);
+
+        let expected = ruler.distance(&POINTS[0], &POINTS[i]);
+        assert_eq_err!(expected, distances[i], 1e-12);
+
+        let expected = ruler.bearing(&POINTS[0], &POINTS[i]);
+        assert!((expected - bearings[i]).abs() <= 1e-9);
+    }
+}
+
+#[test]
+fn test_distances_soa_f32() {
+    let ruler = CheapRuler::<f32>::new(32.8351, DistanceUnit::Kilometers);
+
+    let points: Vec<Point<f32>> = POINTS
+        .iter()
+        .map(|p| point!(x: p.x() as f32, y: p.y() as f32))
+        .collect();
+    let xs: Vec<f32> = points.iter().map(|p| p.x()).collect();
+    let ys: Vec<f32> = points.iter().map(|p| p.y()).collect();
+    let mut distances = vec![0.0; points.len()];
+    let mut bearings = vec![0.0; points.len()];
+    ruler.distances_soa(&points[0], &xs, &ys, &mut distances);
+    ruler.bearings_soa(&points[0], &xs, &ys, &mut bearings);
+
+    for i in 1..points.len() {
+        let expected = ruler.distance(&points[0], &points[i]);
+        assert_eq_err!(expected as f64, distances[i] as f64, 1e-6);
+
+        let expected = ruler.bearing(&points[0], &points[i]);
+        assert!((expected - bearings[i]).abs() <= 1e-4);
+    }
+}
+
+#[test]
+fn test_distances_soa_over_dateline() {
+    let ruler = fixtures::ruler_km();
+
+    let origin = point!(x: 179.9, y: 32.7);
+    let xs = [-179.9; 9];
+    let ys = [32.9; 9];
+    let mut distances = [0.0; 9];
+    ruler.distances_soa(&origin, &xs, &ys, &mut distances);
+
+    for d in distances.iter() {
+        assert_eq_err!(29.05, *d, 0.001);
+    }
+}
+
 #[test]
 fn test_bearing() {
     let ruler = fixtures::ruler_km();
@@ -105,6 +324,31 @@ fn test_line_distance() {
     }
 }
 
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_line_distances() {
+    let ruler = fixtures::ruler_km();
+
+    let actual = ruler.par_line_distances(&LINES);
+
+    for i in 0..LINES.len() {
+        assert_eq!(ruler.line_distance(&LINES[i]), actual[i]);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_distances() {
+    let ruler = fixtures::ruler_km();
+
+    let mut actual = vec![0.0; POINTS.len()];
+    ruler.par_distances(&POINTS[0], &POINTS, &mut actual);
+
+    for i in 0..POINTS.len() {
+        assert_eq!(ruler.distance(&POINTS[0], &POINTS[i]), actual[i]);
+    }
+}
+
 #[test]
 fn test_area() {
     let ruler = fixtures::ruler_km();
@@ -169,6 +413,246 @@ fn test_area_subtractions() {
     assert_eq_err!(expected_subtracted, actual_subtracted, 0.003);
 }
 
+#[test]
+fn test_coords_variants() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let buffer: Vec<[f64; 2]> = line.0.iter().map(|c| [c.x, c.y]).collect();
+        let coords = || buffer.iter().map(|&[x, y]| coord!(x: x, y: y));
+        let dist = ruler.line_distance(line);
+
+        assert_eq!(ruler.line_distance_coords(coords()), dist);
+        assert_eq!(ruler.line_distance_coords(&line.0), dist);
+        assert_eq!(
+            ruler.along_coords(coords(), dist / 3.0),
+            ruler.along(line, dist / 3.0)
+        );
+        let expected = ruler.point_on_line(line, &POINTS[0]).unwrap();
+        let actual = ruler.point_on_line_coords(coords(), &POINTS[0]).unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            ruler.line_slice_coords(&POINTS[0], &POINTS[1], &line.0),
+            ruler.line_slice(&POINTS[0], &POINTS[1], line)
+        );
+        assert_eq!(
+            ruler.line_slice_along_coords(dist / 4.0, dist / 2.0, coords()),
+            ruler.line_slice_along(dist / 4.0, dist / 2.0, line)
+        );
+        assert_eq!(ruler.signed_area_coords(coords()), ruler.signed_area(line));
+    }
+}
+
+#[test]
+fn test_area_coords() {
+    fn coords(ring: &[[f64; 2]]) -> impl Iterator<Item = Coordinate<f64>> + '_ {
+        ring.iter().map(|&[x, y]| coord!(x: x, y: y))
+    }
+
+    let ruler = fixtures::ruler_km();
+    let shell = [[71.3, -6.3], [71.4, -6.3], [71.4, -6.2], [71.3, -6.3]];
+    let hole = [
+        [71.37, -6.27],
+        [71.38, -6.27],
+        [71.38, -6.26],
+        [71.37, -6.27],
+    ];
+
+    let polygon =
+        Polygon::new(coords(&shell).collect(), vec![coords(&hole).collect()]);
+    let actual = ruler.area_coords(coords(&shell), vec![coords(&hole)]);
+
+    assert_eq!(actual, ruler.area(&polygon));
+}
+
+#[test]
+fn test_lng_lat_inputs() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (POINTS[i], POINTS[i + 1]);
+        let expected = ruler.distance(&a, &b);
+
+        assert_eq!(ruler.distance(&a.0, &b.0), expected);
+        assert_eq!(ruler.distance(&(a.x(), a.y()), &[b.x(), b.y()]), expected);
+        assert_eq!(ruler.distance(&&a, &b), expected);
+        assert_eq!(
+            ruler.bearing(&(a.x(), a.y()), &[b.x(), b.y()]),
+            ruler.bearing(&a, &b)
+        );
+        assert_eq!(
+            ruler.destination(&[a.x(), a.y()], 1.0, 30.0),
+            ruler.destination(&a, 1.0, 30.0)
+        );
+    }
+
+    let ruler = CheapRuler::<f32>::new(32.8351, DistanceUnit::Meters);
+    let a = [-96.9203f32, 32.8382];
+    let b = (-96.9204f32, 32.8383);
+    assert_eq!(
+        ruler.distance(&a, &b),
+        ruler.distance(&Point::from(a), &Point::from(b))
+    );
+}
+
+#[test]
+fn test_area_independent_of_winding() {
+    let ruler = fixtures::ruler_km();
+    let shell = line_string![
+        (x: 71.3, y: -6.3),
+        (x: 71.4, y: -6.3),
+        (x: 71.4, y: -6.2),
+        (x: 71.3, y: -6.2),
+        (x: 71.3, y: -6.3),
+    ];
+    let hole = line_string![
+        (x: 71.32, y: -6.27),
+        (x: 71.38, y: -6.27),
+        (x: 71.38, y: -6.22),
+        (x: 71.32, y: -6.22),
+        (x: 71.32, y: -6.27),
+    ];
+    let reversed = |ring: &LineString<f64>| {
+        ring.0.iter().rev().copied().collect::<LineString<f64>>()
+    };
+
+    let expected = ruler.signed_area(&shell) - ruler.signed_area(&hole);
+    for (shell, hole) in [
+        (shell.clone(), hole.clone()),
+        (shell.clone(), reversed(&hole)),
+        (reversed(&shell), hole.clone()),
+        (reversed(&shell), reversed(&hole)),
+    ] {
+        let polygon = Polygon::new(shell, vec![hole]);
+        assert_eq_err!(expected, ruler.area(&polygon), 1e-12);
+    }
+}
+
+#[test]
+fn test_measure_geometries() {
+    let ruler = fixtures::ruler_km();
+    let a = coord!(x: 71.3, y: -6.3);
+    let b = coord!(x: 71.4, y: -6.3);
+    let c = coord!(x: 71.4, y: -6.2);
+    let d = coord!(x: 71.3, y: -6.2);
+
+    let line = Line::new(a, b);
+    let line_string = LineString::from(vec![a, b, c]);
+    let square = Polygon::new(LineString::from(vec![a, b, c, d, a]), vec![]);
+    let triangle = Triangle::new(a, b, c);
+    let rect = geo_types::Rect::new(a, c);
+
+    let line_length = ruler.distance(&a, &b);
+    let line_string_length = ruler.line_distance(&line_string);
+    let square_area = ruler.signed_area(square.exterior());
+    let square_perimeter = ruler.line_distance(square.exterior());
+
+    assert_eq!(ruler.length(&line), line_length);
+    assert_eq!(ruler.length(&line_string), line_string_length);
+    assert_eq!(ruler.perimeter(&square), square_perimeter);
+    assert_eq!(ruler.area(&rect), square_area);
+    assert_eq!(ruler.perimeter(&rect), square_perimeter);
+    assert_eq_err!(square_area / 2.0, ruler.area(&triangle), 1e-12);
+    assert_eq_err!(
+        line_string_length + ruler.distance(&c, &a),
+        ruler.perimeter(&triangle),
+        1e-12
+    );
+
+    let multi_line_string =
+        MultiLineString(vec![line_string.clone(), line_string.clone()]);
+    let multi_polygon = MultiPolygon(vec![square.clone(), square.clone()]);
+    assert_eq!(ruler.length(&multi_line_string), 2.0 * line_string_length);
+    assert_eq!(ruler.area(&multi_polygon), 2.0 * square_area);
+    assert_eq!(ruler.perimeter(&multi_polygon), 2.0 * square_perimeter);
+
+    let collection = GeometryCollection(vec![
+        Geometry::Point(a.into()),
+        Geometry::Line(line),
+        Geometry::LineString(line_string),
+        Geometry::Polygon(square),
+        Geometry::Rect(rect),
+    ]);
+    let geometry = Geometry::GeometryCollection(collection.clone());
+    assert_eq!(ruler.length(&geometry), line_length + line_string_length);
+    assert_eq!(ruler.area(&geometry), 2.0 * square_area);
+    assert_eq!(ruler.perimeter(&collection), 2.0 * square_perimeter);
+}
+
+#[test]
+fn test_measure_zero() {
+    let ruler = fixtures::ruler_km();
+    let a = coord!(x: 71.3, y: -6.3);
+    let b = coord!(x: 71.4, y: -6.3);
+
+    let geometries: Vec<Geometry<f64>> = vec![
+        Geometry::Point(a.into()),
+        Geometry::MultiPoint(MultiPoint(vec![a.into(), b.into()])),
+        Geometry::Line(Line::new(a, b)),
+        Geometry::LineString(LineString::from(vec![a, b])),
+        Geometry::GeometryCollection(GeometryCollection(vec![])),
+    ];
+
+    for geometry in geometries.iter() {
+        assert_eq!(ruler.area(geometry), 0.0);
+        assert_eq!(ruler.perimeter(geometry), 0.0);
+    }
+    assert_eq!(ruler.length(&Point::from(a)), 0.0);
+}
+
+#[test]
+fn test_extension_traits() {
+    let ruler = fixtures::ruler_km();
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (POINTS[i], POINTS[i + 1]);
+        assert_eq!(a.cheap_distance(&b, &ruler), ruler.distance(&a, &b));
+        assert_eq!(a.cheap_bearing(b, &ruler), ruler.bearing(&a, &b));
+        assert_eq!(
+            a.cheap_destination(45.0, 1.0, &ruler),
+            ruler.destination(&a, 1.0, 45.0)
+        );
+        assert_eq!(
+            a.cheap_distance(&Line::new(b, POINTS[0]), &ruler),
+            ruler.point_to_segment_distance(&a, &b, &POINTS[0])
+        );
+    }
+
+    for line in LINES.iter() {
+        let point = POINTS[0];
+        let expected = ruler.distance(
+            &point,
+            &ruler.point_on_line(line, &point).unwrap().point(),
+        );
+        assert_eq!(point.cheap_distance(line, &ruler), expected);
+        assert_eq!(line.cheap_length(&ruler), ruler.line_distance(line));
+        assert_eq!(line.cheap_area(&ruler), 0.0);
+
+        let polygon = Polygon::new(line.clone(), vec![]);
+        assert_eq!(polygon.cheap_area(&ruler), ruler.area(&polygon));
+    }
+
+    let empty: LineString<f64> = line_string![];
+    assert_eq!(POINTS[0].cheap_distance(&empty, &ruler), f64::INFINITY);
+}
+
+#[test]
+fn test_signed_area() {
+    let ruler = fixtures::ruler_km();
+    let ccw = line_string![
+        (x: 71.3, y: -6.3),
+        (x: 71.4, y: -6.3),
+        (x: 71.4, y: -6.2),
+        (x: 71.3, y: -6.3),
+    ];
+    let cw = ccw.0.iter().rev().copied().collect::<LineString<f64>>();
+
+    let polygon = Polygon::new(ccw.clone(), vec![]);
+    assert_eq!(ruler.signed_area(&ccw), ruler.area(&polygon));
+    assert_eq!(ruler.signed_area(&cw), -ruler.area(&polygon));
+    assert_eq!(ruler.signed_area(&line_string![]), 0.0);
+}
+
 #[test]
 fn test_along() {
     let ruler = fixtures::ruler_km();
@@ -210,7 +694,7 @@ fn test_along_with_dist_lt_0() {
 fn test_along_with_dist_greater_than_length() {
     let ruler = fixtures::ruler_km();
 
-    let coord = LINES[0].points().last().expect("Last element");
+    let coord = LINES[0].points().next_back().expect("Last element");
     let actual = ruler
         .along(&LINES[0], 1000.0)
         .expect("Non-empty line string given");
@@ -265,6 +749,598 @@ fn test_point_on_line() {
     );
 }
 
+#[test]
+fn test_point_on_line_side() {
+    let ruler = fixtures::ruler_km();
+
+    let line = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+    ];
+    let point = point!(x: -77.034076, y: 38.882017);
+    let result = ruler.point_on_line(&line, &point).unwrap();
+
+    assert_eq_err!(
+        ruler.distance(&point, &result.point()),
+        result.distance(),
+        1e-9
+    );
+    assert_eq_err!(
+        ruler.distance(&line[0], &result.point()),
+        result.along(),
+        1e-9
+    );
+    assert_eq!(ruler.bearing(&line[0], &line[1]), result.bearing());
+    assert_eq!(Side::Left, result.side());
+
+    let right = ruler.point_on_line(&line, &(-77.0291, 38.8794)).unwrap();
+    assert_eq!(Side::Right, right.side());
+
+    let on = ruler.point_on_line(&line, &result.point()).unwrap();
+    assert!(on.distance() < 1e-9);
+
+    let single = line_string![(x: -77.031669, y: 38.878605)];
+    let result = ruler.point_on_line(&single, &point).unwrap();
+    assert_eq!(Point::from(single[0]), result.point());
+    assert_eq!(ruler.distance(&single[0], &point), result.distance());
+    assert_eq!(0.0, result.along());
+    assert_eq!(Side::On, result.side());
+}
+
+#[test]
+fn test_point_on_line_along() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        for point in POINTS.iter() {
+            let result = ruler.point_on_line(line, point).unwrap();
+            let prefix = ruler.line_slice(&Point::from(line[0]), point, line);
+            assert_eq_err!(ruler.line_distance(&prefix), result.along(), 1e-9);
+            assert_eq_err!(
+                ruler.point_to_segment_distance(
+                    point,
+                    &line[result.index()],
+                    &line[result.index() + 1]
+                ),
+                result.distance(),
+                1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn test_point_on_line_repeated_vertex() {
+    let ruler = CheapRuler::new(45.0, DistanceUnit::Meters);
+    let line = line_string![
+        (x: 15.0, y: 45.0),
+        (x: 15.0, y: 45.0),
+        (x: 15.01, y: 45.0),
+        (x: 15.01, y: 45.0),
+    ];
+    let point = ruler.destination(&(15.0, 45.0), 5.0, 0.0);
+
+    let result = ruler.point_on_line(&line, &point).unwrap();
+    assert_eq!(result.index(), 1);
+    assert_eq!(result.side(), Side::Left);
+    assert_eq_err!(90.0, result.bearing(), 1e-9);
+    assert_eq_err!(5.0, result.distance(), 1e-9);
+
+    let measured = MeasuredLine::new(&ruler, line.clone());
+    let length = ruler.line_distance(&line);
+    for &dist in [-1.0, 0.0, length, length + 1.0].iter() {
+        let located = ruler.locate_along(&line, dist).unwrap();
+        assert_eq!(located.index(), 1);
+        assert_eq_err!(90.0, located.bearing(), 1e-9);
+        assert_eq!(measured.locate_along(dist).unwrap(), located);
+    }
+}
+
+#[test]
+fn test_station_offset() {
+    let ruler = fixtures::ruler_km();
+
+    let line = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+        (x: -77.025609, y: 38.881946),
+    ];
+    let length = ruler.line_distance(&line);
+
+    for &station in [0.1, 0.3, 0.5].iter() {
+        for &offset in [-0.05, -0.01, 0.0, 0.01, 0.05].iter() {
+            let point =
+                ruler.from_station_offset(&line, station, offset).unwrap();
+            let actual = ruler.station_offset(&line, &point).unwrap();
+
+            assert_eq_err!(station, actual.station(), 1e-6);
+            assert!(
+                (offset - actual.offset()).abs() < 1e-9,
+                "{} != {}",
+                offset,
+                actual.offset()
+            );
+        }
+    }
+
+    // the second segment runs east, so north of it is left
+    let north = ruler
+        .from_station_offset(&line, length - 0.1, 0.02)
+        .unwrap();
+    assert!(north.y() > 38.881946);
+    assert_eq!(
+        Side::Left,
+        ruler.point_on_line(&line, &north).unwrap().side()
+    );
+
+    let on_line = ruler.station_offset(&line, &line[1]).unwrap();
+    assert_eq!(0.0, on_line.offset());
+
+    let empty = line_string![];
+    assert!(ruler.station_offset(&empty, &POINTS[0]).is_none());
+    assert!(ruler.from_station_offset(&empty, 1.0, 1.0).is_none());
+}
+
+#[test]
+fn test_station_offset_repeated_vertex() {
+    let ruler = CheapRuler::new(45.0, DistanceUnit::Meters);
+    let line = line_string![
+        (x: 15.0, y: 45.0),
+        (x: 15.0, y: 45.0),
+        (x: 15.01, y: 45.0),
+    ];
+    let north = ruler.destination(&(15.0, 45.0), 5.0, 0.0);
+
+    let actual = ruler.station_offset(&line, &north).unwrap();
+    assert_eq_err!(0.0, actual.station(), 1e-9);
+    assert_eq_err!(5.0, actual.offset(), 1e-9);
+
+    let point = ruler.from_station_offset(&line, 0.0, 5.0).unwrap();
+    assert!(ruler.distance(&point, &north) < 1e-9);
+}
+
+#[test]
+fn test_station_offset_beyond_ends() {
+    let ruler = CheapRuler::new(45.0, DistanceUnit::Meters);
+    let line = line_string![(x: 15.0, y: 45.0), (x: 15.01, y: 45.0)];
+    let length = ruler.line_distance(&line);
+
+    let before = ruler.destination(&(15.0, 45.0), 5.0, 270.0);
+    assert_eq!(ruler.station_offset(&line, &before), None);
+
+    let after = ruler.destination(&(15.01, 45.0), 5.0, 90.0);
+    assert_eq!(ruler.station_offset(&line, &after), None);
+
+    let beside = ruler.destination(&(15.01, 45.0), 100.0, 45.0);
+    assert_eq!(ruler.station_offset(&line, &beside), None);
+
+    // points level with the ends are still alongside the line
+    for &(station, offset) in [(0.0, 5.0), (length, -5.0)].iter() {
+        let point = ruler.from_station_offset(&line, station, offset).unwrap();
+        let actual = ruler.station_offset(&line, &point).unwrap();
+        assert_eq_err!(station, actual.station(), 1e-9);
+        assert_eq_err!(offset, actual.offset(), 1e-9);
+    }
+
+    let end = ruler.station_offset(&line, &(15.01, 45.0)).unwrap();
+    assert_eq!(end.offset(), 0.0);
+}
+
+#[test]
+fn test_local_frame() {
+    let ruler = fixtures::ruler_km();
+    let frame = LocalFrame::new(&ruler, &POINTS[0]);
+
+    for point in POINTS.iter() {
+        let projected = frame.project(point);
+        assert_eq_err!(
+            ruler.distance(&POINTS[0], point),
+            projected.x().hypot(projected.y()),
+            1e-12
+        );
+        assert_eq!(
+            ruler.offset(&POINTS[0], projected.x(), projected.y()),
+            frame.unproject(&projected)
+        );
+
+        let unprojected = frame.unproject(&projected);
+        assert!((unprojected.x() - point.x()).abs() < 1e-12);
+        assert!((unprojected.y() - point.y()).abs() < 1e-12);
+    }
+
+    // the same as mapping with geo, whose trait can be imported alongside
+    let projected = LINES[0].map_coords(|c| frame.project(&c).0);
+    assert_eq!(frame.project_geometry(&LINES[0]), projected);
+    assert_eq!(LINES[0].cheap_map_coords(&|c| c), LINES[0]);
+
+    let polygon = Polygon::new(LINES[0].clone(), vec![LINES[1].clone()]);
+    let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+        Geometry::Polygon(polygon),
+        Geometry::Point(POINTS[1]),
+    ]));
+    let projected = frame.project_geometry(&geometry);
+    let unprojected = frame.unproject_geometry(&projected);
+    match (&projected, &unprojected) {
+        (
+            Geometry::GeometryCollection(projected),
+            Geometry::GeometryCollection(unprojected),
+        ) => {
+            assert_eq!(
+                Geometry::Point(frame.project(&POINTS[1])),
+                projected.0[1]
+            );
+            assert_eq!(2, unprojected.0.len());
+        }
+        _ => panic!("geometry type changed"),
+    }
+
+    let line = frame.project_geometry(&LINES[0]);
+    for (expected, actual) in LINES[0]
+        .points()
+        .zip(frame.unproject_geometry(&line).points())
+    {
+        assert!((expected.x() - actual.x()).abs() < 1e-12);
+        assert!((expected.y() - actual.y()).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_local_frame_dateline() {
+    let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    let frame = LocalFrame::new(&ruler, &(179.9, 0.0));
+
+    let projected = frame.project(&(-179.9, 0.0));
+    let dist = ruler.distance(&(179.9, 0.0), &(-179.9, 0.0));
+    assert_eq_err!(dist, projected.x(), 1e-12);
+
+    let unprojected = frame.unproject(&projected);
+    assert!((unprojected.x() + 179.9).abs() < 1e-9);
+}
+
+#[test]
+fn test_translate() {
+    let ruler = fixtures::ruler_km();
+
+    let line = &LINES[0];
+    let moved = ruler.translate(line, 0.3, -0.4);
+    for (a, b) in line.points().zip(moved.points()) {
+        assert_eq!(ruler.offset(&a, 0.3, -0.4), b);
+        assert_eq_err!(0.5, ruler.distance(&a, &b), 1e-12);
+    }
+    assert_eq_err!(
+        ruler.line_distance(line),
+        ruler.line_distance(&moved),
+        1e-9
+    );
+
+    let moved = ruler.translate_by(line, 0.5, 30.0);
+    for (a, b) in line.points().zip(moved.points()) {
+        assert_eq!(ruler.destination(&a, 0.5, 30.0), b);
+    }
+
+    // a rectangle moved across the antimeridian keeps its corners, without
+    // wrapping them
+    let rect = geo_types::Rect::new((179.99, 32.8), (179.995, 32.805));
+    let moved = ruler.translate(&Geometry::Rect(rect), 1.0, 0.0);
+    match moved {
+        Geometry::Polygon(moved) => {
+            let corners = rect.to_polygon();
+            for (a, b) in
+                corners.exterior().points().zip(moved.exterior().points())
+            {
+                assert!(b.x() > 180.0);
+                assert!(ruler.distance(&ruler.offset(&a, 1.0, 0.0), &b) < 1e-9);
+            }
+        }
+        _ => panic!("expected a polygon"),
+    }
+
+    // geometries keep their longitude frame
+    let polygon = polygon![
+        (x: 179.9, y: 32.7),
+        (x: 180.1, y: 32.7),
+        (x: 180.1, y: 32.9),
+        (x: 179.9, y: 32.9),
+    ];
+    assert_eq!(polygon, ruler.translate(&polygon, 0.0, 0.0));
+    let pivot = point!(x: 180.0, y: 32.8);
+    for (a, b) in polygon
+        .exterior()
+        .points()
+        .zip(ruler.rotate(&polygon, &pivot, 0.0).exterior().points())
+    {
+        assert_eq_err!(a.x(), b.x(), 1e-12);
+        assert_eq_err!(a.y(), b.y(), 1e-12);
+    }
+    let scaled = ruler.scale(&polygon, &pivot, 1.0);
+    assert!(scaled.exterior().points().all(|p| p.x() > 179.0));
+}
+
+#[test]
+fn test_rotate_and_scale() {
+    let ruler = fixtures::ruler_km();
+
+    let pivot = POINTS[0];
+    let polygon = Polygon::new(LINES[0].clone(), vec![]);
+    let area = ruler.area(&polygon);
+
+    let rotated = ruler.rotate(&polygon, &pivot, 90.0);
+    assert_eq_err!(area, ruler.area(&rotated), 1e-6);
+    for (a, b) in polygon.exterior().points().zip(rotated.exterior().points()) {
+        assert_eq_err!(
+            ruler.distance(&pivot, &a),
+            ruler.distance(&pivot, &b),
+            1e-9
+        );
+        if ruler.distance(&pivot, &a) < 1e-9 {
+            continue;
+        }
+        let turn = ruler.bearing(&pivot, &b) - ruler.bearing(&pivot, &a);
+        let turn = (turn + 360.0) % 360.0;
+        assert!((turn - 90.0).abs() < 1e-6, "{}", turn);
+    }
+
+    let north = ruler.destination(&pivot, 1.0, 0.0);
+    let east = ruler.rotate(&north, &pivot, 90.0);
+    assert_eq_err!(90.0, ruler.bearing(&pivot, &east), 1e-9);
+
+    let scaled = ruler.scale(&polygon, &pivot, 2.0);
+    assert_eq_err!(area * 4.0, ruler.area(&scaled), 1e-6);
+    assert_eq!(pivot, ruler.scale(&pivot, &pivot, 2.0));
+
+    let rect = geo_types::Rect::new(LINES[1][0], LINES[1][1]);
+    let area = ruler.area(&rect.to_polygon());
+    match ruler.rotate(&Geometry::Rect(rect), &pivot, 30.0) {
+        Geometry::Polygon(rotated) => {
+            assert_eq_err!(area, ruler.area(&rotated), 1e-6);
+        }
+        _ => panic!("expected a polygon"),
+    }
+
+    let line =
+        ruler.rotate(&Geometry::LineString(LINES[1].clone()), &pivot, 0.0);
+    match line {
+        Geometry::LineString(line) => {
+            for (a, b) in LINES[1].points().zip(line.points()) {
+                assert!(ruler.distance(&a, &b) < 1e-9);
+            }
+        }
+        _ => panic!("geometry type changed"),
+    }
+}
+
+#[test]
+fn test_simplify() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        for &tolerance in [0.01, 0.1, 1.0].iter() {
+            let simplified = ruler.simplify(line, tolerance);
+            assert_eq!(line.0.first(), simplified.0.first());
+            assert_eq!(line.0.last(), simplified.0.last());
+            for point in line.points() {
+                let pol = ruler.point_on_line(&simplified, &point).unwrap();
+                assert!(pol.distance() <= tolerance + 1e-12);
+            }
+
+            // the same as simplifying in a local frame with geo
+            let frame = LocalFrame::new(&ruler, &line[0]);
+            let expected = frame.project_geometry(line).simplify(&tolerance);
+            assert_eq!(expected.0.len(), simplified.0.len());
+            assert_eq!(line.cheap_simplify(&ruler, tolerance), simplified);
+        }
+    }
+
+    let lines = MultiLineString(LINES[..3].to_vec());
+    let simplified = ruler.simplify(&lines, 0.1);
+    for (line, actual) in lines.0.iter().zip(simplified.0.iter()) {
+        assert_eq!(&ruler.simplify(line, 0.1), actual);
+    }
+}
+
+#[test]
+fn test_simplify_polygon() {
+    let ruler = CheapRuler::new(45.0, DistanceUnit::Meters);
+    let frame = LocalFrame::new(&ruler, &(15.0, 45.0));
+
+    // the notch in the exterior ring reaches below the hole
+    let polygon: Polygon<f64> = frame.unproject_geometry(&Polygon::new(
+        line_string![
+            (x: 0.0, y: 10.0),
+            (x: 50.0, y: 2.0),
+            (x: 100.0, y: 10.0),
+            (x: 100.0, y: 100.0),
+            (x: 0.0, y: 100.0),
+            (x: 0.0, y: 10.0),
+        ],
+        vec![line_string![
+            (x: 47.0, y: 5.0),
+            (x: 53.0, y: 5.0),
+            (x: 53.0, y: 12.0),
+            (x: 47.0, y: 12.0),
+            (x: 47.0, y: 5.0),
+        ]],
+    ));
+
+    let simplified = ruler.simplify(&polygon, 9.0);
+    assert_eq!(5, simplified.exterior().0.len());
+    assert_eq!(4, simplified.interiors()[0].0.len());
+
+    let preserved = ruler.simplify_preserve_topology(&polygon, 9.0);
+    assert_eq!(polygon, preserved);
+
+    // rings keep at least four coordinates
+    let collapsed = ruler.simplify(&polygon, 1000.0);
+    assert_eq!(4, collapsed.exterior().0.len());
+    assert!(ruler.area(&collapsed) > 0.0);
+
+    let polygons = MultiPolygon(vec![polygon.clone(), polygon]);
+    let simplified = ruler.simplify(&polygons, 9.0);
+    assert_eq!(2, simplified.0.len());
+    assert_eq!(1, simplified.0[1].interiors().len());
+}
+
+#[test]
+fn test_simplify_vw() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let n = line.0.len();
+        let mut previous: Option<Vec<usize>> = None;
+
+        for count in (2..=n).rev() {
+            let simplified =
+                ruler.simplify_vw(line, VwTarget::VertexCount(count));
+            let indices = simplified.indices();
+            assert_eq!(count, indices.len());
+            assert_eq!(Some(&0), indices.first());
+            assert_eq!(Some(&(n - 1)), indices.last());
+            for (&i, c) in indices.iter().zip(simplified.line().0.iter()) {
+                assert_eq!(&line[i], c);
+            }
+
+            // vertices are removed in order of importance
+            if let Some(previous) = previous {
+                assert!(indices.iter().all(|i| previous.contains(i)));
+            }
+            previous = Some(indices.to_vec());
+        }
+
+        let all = ruler.simplify_vw(line, VwTarget::Area(-1.0));
+        assert_eq!(line, all.line());
+        let ends = ruler.simplify_vw(line, VwTarget::Area(f64::INFINITY));
+        assert_eq!(2.min(n), ends.into_line().0.len());
+        for &count in [0, 1].iter() {
+            let ends = ruler.simplify_vw(line, VwTarget::VertexCount(count));
+            assert_eq!(2.min(n), ends.indices().len());
+        }
+    }
+
+    // triangle areas are measured like polygon areas
+    let line = line_string![
+        (x: -77.031669, y: 38.878605),
+        (x: -77.029609, y: 38.881946),
+        (x: -77.025609, y: 38.881946),
+    ];
+    let area = ruler.area(&Polygon::new(line.clone(), vec![]));
+    let kept = ruler.simplify_vw(&line, VwTarget::Area(area * 0.999));
+    assert_eq!(&[0, 1, 2], kept.indices());
+    let removed = ruler.simplify_vw(&line, VwTarget::Area(area * 1.001));
+    assert_eq!(&[0, 2], removed.indices());
+
+    let collinear = line_string![
+        (x: -77.0, y: 38.0),
+        (x: -77.0, y: 38.5),
+        (x: -77.0, y: 39.0),
+    ];
+    let simplified = ruler.simplify_vw(&collinear, VwTarget::Area(0.0));
+    assert_eq!(&[0, 2], simplified.indices());
+
+    let empty = line_string![];
+    let simplified = ruler.simplify_vw(&empty, VwTarget::VertexCount(0));
+    assert!(simplified.indices().is_empty());
+}
+
+#[test]
+fn test_densify() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let densified = ruler.densify(line, 0.01);
+        assert_eq_err!(
+            ruler.line_distance(line),
+            ruler.line_distance(&densified),
+            1e-9
+        );
+        for segment in densified.lines() {
+            assert!(ruler.distance(&segment.start, &segment.end) <= 0.01);
+        }
+
+        // existing vertices are kept in order
+        let mut vertices = densified.0.iter();
+        for c in line.0.iter() {
+            assert!(vertices.any(|v| v == c));
+        }
+    }
+
+    let short = ruler.densify(&LINES[0], 1000.0);
+    assert_eq!(LINES[0], short);
+    assert_eq!(line_string![], ruler.densify(&line_string![], 1.0));
+
+    let ruler = CheapRuler::<f32>::new(45.0, DistanceUnit::Meters);
+    let line = line_string![(x: 15.0f32, y: 45.0), (x: 15.0, y: 45.1)];
+    let pieces = (ruler.line_distance(&line) / 10.0).ceil() as usize;
+    assert_eq!(pieces + 1, ruler.densify(&line, 10.0).0.len());
+    let steps = ruler.line_distance(&line) / 10.0;
+    assert_eq!(
+        steps.ceil() as usize + 1,
+        ruler.resample(&line, 10.0).0.len()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Segment can't be split into that many pieces")]
+fn test_densify_too_many_pieces() {
+    let ruler = fixtures::ruler_km();
+    ruler.densify(&LINES[0], 1e-300);
+}
+
+#[test]
+fn test_resample() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let length = ruler.line_distance(line);
+        let resampled = ruler.resample(line, 0.05);
+        let last = resampled.0.len() - 1;
+
+        assert_eq!(line.0.last(), resampled.0.last());
+        for (i, point) in resampled.points().take(last).enumerate() {
+            let dist = i as f64 * 0.05;
+            assert!(dist < length || length == 0.0);
+            assert_eq!(ruler.along(line, dist), Some(point));
+        }
+        if line.0.len() > 1 {
+            assert!((last - 1) as f64 * 0.05 < length);
+            assert!(last as f64 * 0.05 >= length);
+        }
+    }
+
+    let single = line_string![(x: -77.0, y: 38.0)];
+    assert_eq!(single, ruler.resample(&single, 1.0));
+    assert_eq!(line_string![], ruler.resample(&line_string![], 1.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_point_on_line_serde() {
+    let ruler = fixtures::ruler_km();
+
+    let result = ruler.point_on_line(&LINES[0], &POINTS[0]).unwrap();
+    let json = serde_json::to_string(&result).unwrap();
+    let actual: cheap_ruler::PointOnLine<f64> =
+        serde_json::from_str(&json).unwrap();
+    assert_eq!(result, actual);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_points_on_line() {
+    let ruler = fixtures::ruler_km();
+
+    let line = &LINES[0];
+    let actual = ruler.par_points_on_line(line, &POINTS);
+
+    for i in 0..POINTS.len() {
+        let expected = ruler
+            .point_on_line(line, &POINTS[i])
+            .expect("Non-empty line string given");
+        let actual = actual[i].as_ref().expect("Non-empty line string given");
+
+        assert_eq!(&expected, actual);
+    }
+}
+
 #[test]
 fn test_point_to_segment_distance() {
     let ruler = fixtures::ruler_km();
@@ -420,3 +1496,309 @@ fn test_from_tile() {
         2e-5
     );
 }
+
+#[test]
+fn test_try_new() {
+    assert_eq!(
+        CheapRuler::try_new(50.5, DistanceUnit::Kilometers),
+        Ok(CheapRuler::new(50.5, DistanceUnit::Kilometers))
+    );
+    assert_eq!(
+        CheapRuler::try_new(-90.0, DistanceUnit::Miles),
+        Ok(CheapRuler::new(-90.0, DistanceUnit::Miles))
+    );
+    assert_eq!(
+        CheapRuler::try_new(90.5, DistanceUnit::Meters),
+        Err(Error::InvalidLatitude(90.5))
+    );
+    assert_eq!(
+        CheapRuler::try_new(f64::INFINITY, DistanceUnit::Meters),
+        Err(Error::InvalidLatitude(f64::INFINITY))
+    );
+    assert!(matches!(
+        CheapRuler::try_new(f32::NAN, DistanceUnit::Meters),
+        Err(Error::InvalidLatitude(latitude)) if latitude.is_nan()
+    ));
+}
+
+#[test]
+fn test_try_from_tile() {
+    assert_eq!(
+        CheapRuler::try_from_tile(11041, 15, DistanceUnit::Kilometers),
+        Ok(CheapRuler::<f64>::from_tile(
+            11041,
+            15,
+            DistanceUnit::Kilometers
+        ))
+    );
+    assert_eq!(
+        CheapRuler::<f64>::try_from_tile(0, 32, DistanceUnit::Meters),
+        Err(Error::InvalidZoom(32))
+    );
+    assert_eq!(
+        CheapRuler::<f64>::try_from_tile(4, 2, DistanceUnit::Meters),
+        Err(Error::TileOutOfRange { y: 4, z: 2 })
+    );
+    assert!(
+        CheapRuler::<f32>::try_from_tile(3, 2, DistanceUnit::Meters).is_ok()
+    );
+}
+
+#[test]
+fn test_error_estimate() {
+    for &latitude in [-50.0, 0.0, 32.8351, 60.0, 80.0].iter() {
+        for &span in [0.1, 1.0, 5.0].iter() {
+            let ruler = CheapRuler::new(latitude, DistanceUnit::Kilometers);
+            let geodesic = Geodesic::new(DistanceUnit::Kilometers);
+            let bbox = Rect::new(
+                Coordinate {
+                    x: 30.0 - span,
+                    y: latitude - span / 2.0,
+                },
+                Coordinate {
+                    x: 30.0 + span,
+                    y: latitude + span / 2.0,
+                },
+            );
+            let estimate = ruler.error_estimate(&bbox);
+
+            let points: Vec<Point<f64>> = (0..16)
+                .map(|i| {
+                    point!(
+                        x: 30.0 - span + span * (i % 4) as f64 / 1.5,
+                        y: latitude - span / 2.0 + span * (i / 4) as f64 / 3.0
+                    )
+                })
+                .collect();
+            for a in points.iter() {
+                for b in points.iter().filter(|b| *b != a) {
+                    let exact = geodesic.distance(a, b).unwrap();
+                    let error = (ruler.distance(a, b) / exact - 1.0).abs();
+
+                    // allow for the convergence tolerance of the reference
+                    assert!(error <= estimate.distance() + 1e-9);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_error_estimate_city_scale() {
+    let ruler = fixtures::ruler_km();
+
+    let estimate =
+        ruler.error_estimate_for_span(&point!(x: -96.92, y: 32.8351), 5.0);
+    assert!(estimate.within(0.001));
+    assert!(estimate.area() > 0.0);
+
+    let estimate =
+        ruler.error_estimate_for_span(&point!(x: -96.92, y: 32.8351), 1000.0);
+    assert!(!estimate.within(0.001));
+}
+
+#[test]
+fn test_geodesic_matches_vincenty() {
+    let geodesic = Geodesic::new(DistanceUnit::Kilometers);
+
+    for i in 0..POINTS.len() - 1 {
+        let expected = POINTS[i].vincenty_distance(&POINTS[i + 1]).unwrap();
+        let actual = geodesic.distance(&POINTS[i], &POINTS[i + 1]).unwrap();
+
+        assert!((expected / 1000.0 - actual).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_cheap_ruler_against_geodesic() {
+    let ruler = fixtures::ruler_km();
+    let geodesic = Geodesic::new(DistanceUnit::Kilometers);
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (&POINTS[i], &POINTS[i + 1]);
+        let exact = geodesic.inverse(a, b).expect("Converges");
+        if exact.distance() == 0.0 {
+            continue;
+        }
+
+        assert_eq_err!(exact.distance(), ruler.distance(a, b), 0.003);
+        assert!((exact.initial_bearing() - ruler.bearing(a, b)).abs() < 0.05);
+
+        let expected = geodesic
+            .destination(a, 1.0, exact.initial_bearing())
+            .expect("Converges");
+        let actual = ruler.destination(a, 1.0, exact.initial_bearing());
+        assert!(ruler.distance(&expected, &actual) < 0.003);
+    }
+}
+
+#[test]
+fn test_with_ellipsoid() {
+    let wgs84 = CheapRuler::with_ellipsoid(
+        32.8351,
+        DistanceUnit::Meters,
+        Ellipsoid::WGS84,
+    );
+    assert_eq!(wgs84, CheapRuler::new(32.8351, DistanceUnit::Meters));
+
+    let clarke = CheapRuler::with_ellipsoid(
+        32.8351,
+        DistanceUnit::Meters,
+        Ellipsoid::CLARKE_1866,
+    );
+    let a = point!(x: -96.920341, y: 32.838261);
+    let b = point!(x: -96.920421, y: 32.838295);
+    assert_ne!(wgs84.distance(&a, &b), clarke.distance(&a, &b));
+    assert_eq_err!(wgs84.distance(&a, &b), clarke.distance(&a, &b), 0.0001);
+}
+
+#[test]
+fn test_sphere_against_geodesic() {
+    let ruler = CheapRuler::with_ellipsoid(
+        POINTS[0].y(),
+        DistanceUnit::Kilometers,
+        Ellipsoid::SPHERE,
+    );
+    let geodesic =
+        Geodesic::with_ellipsoid(DistanceUnit::Kilometers, Ellipsoid::SPHERE);
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (&POINTS[i], &POINTS[i + 1]);
+        let exact = geodesic.distance(a, b).expect("Converges");
+        if exact == 0.0 {
+            continue;
+        }
+
+        assert_eq_err!(exact, ruler.distance(a, b), 0.003);
+    }
+}
+
+#[test]
+fn test_haversine_matches_geo() {
+    let haversine = Haversine::new(DistanceUnit::Meters);
+
+    for i in 0..POINTS.len() - 1 {
+        let (a, b) = (&POINTS[i], &POINTS[i + 1]);
+        let expected = a.haversine_distance(b);
+        let actual = haversine.distance(a, b);
+
+        assert!((expected - actual).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_metric_implementations_agree() {
+    fn line_length<M: Metric<f64>>(metric: &M, line: &LineString<f64>) -> f64 {
+        line.lines()
+            .map(|l| metric.distance(&l.start_point(), &l.end_point()))
+            .fold(0.0, |acc, d| acc + d)
+    }
+
+    let ruler = fixtures::ruler_km();
+    let haversine = Haversine::new(DistanceUnit::Kilometers);
+
+    for line in LINES.iter() {
+        let cheap = line_length(&ruler, line);
+        assert_eq!(cheap, ruler.line_distance(line));
+        if cheap > 0.0 {
+            assert_eq_err!(line_length(&haversine, line), cheap, 0.005);
+        }
+    }
+
+    let origin = POINTS[0];
+    let cheap = Metric::offset(&ruler, &origin, 3.0, 4.0);
+    let exact = Metric::offset(&haversine, &origin, 3.0, 4.0);
+    assert!(ruler.distance(&cheap, &exact) < 5.0 * 0.005);
+}
+
+#[test]
+fn test_measured_line() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let measured = MeasuredLine::new(&ruler, line.clone());
+        let length = ruler.line_distance(line);
+
+        assert_eq!(measured.length(), length);
+        assert_eq!(measured.measures().len(), line.0.len());
+        assert_eq!(measured.measures()[0], 0.0);
+
+        for &f in [-0.1, 0.0, 0.2, 0.25, 0.5, 0.9, 1.0, 1.5].iter() {
+            let dist = length * f;
+            assert_eq!(measured.along(dist), ruler.along(line, dist));
+
+            for &g in [-0.1, 0.1, 0.3, 0.6, 1.0, 2.0].iter() {
+                let stop = length * g;
+                assert_eq!(
+                    measured.line_slice_along(dist, stop),
+                    ruler.line_slice_along(dist, stop, line)
+                );
+            }
+        }
+
+        for (i, &m) in measured.measures().iter().enumerate().skip(1) {
+            assert_eq!(measured.along(m), ruler.along(line, m));
+            assert_eq!(m, ruler.line_distance_coords(&line.0[..=i]));
+        }
+
+        assert_eq!(measured.along(f64::NAN), ruler.along(line, f64::NAN));
+        assert_eq!(
+            measured.locate_along(f64::NAN),
+            ruler.locate_along(line, f64::NAN)
+        );
+        for &(start, stop) in [(f64::NAN, length), (0.0, f64::NAN)].iter() {
+            assert_eq!(
+                measured.line_slice_along(start, stop),
+                ruler.line_slice_along(start, stop, line)
+            );
+        }
+    }
+
+    let empty = MeasuredLine::new(&ruler, line_string![]);
+    assert_eq!(empty.length(), 0.0);
+    assert_eq!(empty.along(1.0), None);
+    assert_eq!(empty.line_slice_along(0.0, 1.0), line_string![]);
+}
+
+#[test]
+fn test_distance_along() {
+    let ruler = fixtures::ruler_km();
+
+    for line in LINES.iter() {
+        let measured = MeasuredLine::new(&ruler, line.clone());
+        let length = ruler.line_distance(line);
+
+        for &f in [-0.5, 0.0, 0.1, 0.5, 0.75, 1.0, 2.0].iter() {
+            let dist = length * f;
+            let pol = ruler.locate_along(line, dist).unwrap();
+            assert_eq!(Some(pol.point()), ruler.along(line, dist));
+
+            let measured_pol = measured.locate_along(dist).unwrap();
+            assert_eq!(measured_pol, pol);
+
+            let station = ruler.distance_along(line, &pol.point()).unwrap();
+            assert_eq!(measured.distance_along(&pol.point()), Some(station));
+            if length > 0.0 {
+                assert!(
+                    (station - dist.max(0.0).min(length)).abs() < 1e-9,
+                    "{} != {}",
+                    station,
+                    dist
+                );
+            }
+        }
+
+        let (start, point) = (Point::from(line[0]), POINTS[0]);
+        let pol = ruler.point_on_line(line, &point).unwrap();
+        let station = ruler.distance_along(line, &point).unwrap();
+        let prefix = ruler.line_slice(&start, &point, line);
+        assert_eq_err!(ruler.line_distance(&prefix), station, 1e-9);
+
+        let along = ruler.along(line, station).unwrap();
+        assert!(ruler.distance(&along, &pol.point()) < 1e-9);
+    }
+
+    let empty = line_string![];
+    assert_eq!(ruler.distance_along(&empty, &POINTS[0]), None);
+    assert!(ruler.locate_along(&empty, 1.0).is_none());
+}